@@ -0,0 +1,263 @@
+//! Authentication methods for [`IgrisClient`](crate::IgrisClient).
+//!
+//! Every request asks a [`CredentialProvider`] for the bearer token to send.
+//! API keys and OAuth2 are built in; implement the trait to source tokens from
+//! elsewhere (a secrets manager, a sidecar, a rotating file, ...).
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, CONTENT_TYPE};
+use serde::Deserialize;
+
+use crate::errors::IgrisError;
+use crate::transport::{Transport, TransportRequest};
+
+/// Tokens are refreshed this long before they expire.
+const REFRESH_MARGIN: Duration = Duration::from_secs(30);
+
+/// Lifetime assumed when the token endpoint omits `expires_in`, which RFC 6749
+/// allows.
+const DEFAULT_TOKEN_LIFETIME: Duration = Duration::from_secs(300);
+
+/// Boxed future returned by [`CredentialProvider::token`].
+pub type CredentialFuture<'a> = Pin<Box<dyn Future<Output = Result<String, IgrisError>> + Send + 'a>>;
+
+/// Supplies the bearer token attached to each request.
+///
+/// The client calls [`CredentialProvider::token`] before every request and does
+/// not cache the result, so providers that fetch tokens remotely should cache
+/// (and refresh ahead of expiry) themselves. Use [`IgrisError::Auth`] for
+/// credential failures; like any other request error, it reaches the caller
+/// wrapped in [`IgrisError::Context`] naming the request, so match on
+/// [`IgrisError::root`] to inspect it.
+pub trait CredentialProvider: Send + Sync {
+    fn token(&self) -> CredentialFuture<'_>;
+
+    /// Called when the gateway rejects a token with 401 or 403. Caching
+    /// providers should drop the cached token so the next call fetches a
+    /// fresh one. The default does nothing.
+    fn invalidate(&self) {}
+}
+
+/// Provider that always returns the same token, e.g. an API key.
+pub struct StaticToken(String);
+
+impl StaticToken {
+    pub fn new(token: impl Into<String>) -> Self {
+        Self(token.into())
+    }
+}
+
+impl CredentialProvider for StaticToken {
+    fn token(&self) -> CredentialFuture<'_> {
+        Box::pin(async move { Ok(self.0.clone()) })
+    }
+}
+
+/// How the client authenticates to the gateway.
+#[derive(Clone)]
+pub enum AuthMethod {
+    /// Static API key sent as a bearer token.
+    ApiKey(String),
+    /// OAuth2 client-credentials grant. The client fetches an access token from
+    /// `token_url`, caches it, and refreshes it shortly before it expires.
+    OAuth2 {
+        token_url: String,
+        client_id: String,
+        client_secret: String,
+        scopes: Vec<String>,
+    },
+    /// Custom token source.
+    Custom(Arc<dyn CredentialProvider>),
+}
+
+impl std::fmt::Debug for AuthMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthMethod::ApiKey(key) => f.debug_tuple("ApiKey").field(&mask(key)).finish(),
+            AuthMethod::OAuth2 { token_url, client_id, client_secret: _, scopes } => f
+                .debug_struct("OAuth2")
+                .field("token_url", token_url)
+                .field("client_id", client_id)
+                .field("client_secret", &"[REDACTED]")
+                .field("scopes", scopes)
+                .finish(),
+            AuthMethod::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Render a secret for `Debug` output: the last four characters of long keys
+/// (enough to tell keys apart), nothing at all of short ones.
+pub(crate) fn mask(secret: &str) -> String {
+    let chars = secret.chars().count();
+    if chars < 16 {
+        return "[REDACTED]".to_string();
+    }
+    let tail: String = secret.chars().skip(chars - 4).collect();
+    format!("...{}", tail)
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+struct CachedToken {
+    value: String,
+    refresh_at: Instant,
+}
+
+/// Fetches and caches OAuth2 client-credentials tokens.
+///
+/// Concurrent callers wait on one in-flight refresh rather than each fetching
+/// their own token.
+pub(crate) struct OAuth2TokenSource {
+    transport: Arc<dyn Transport>,
+    token_url: String,
+    client_id: String,
+    client_secret: String,
+    scopes: Vec<String>,
+    cached: tokio::sync::Mutex<Option<CachedToken>>,
+    /// Set by `invalidate`; checked under the lock so the next caller refetches.
+    stale: AtomicBool,
+}
+
+impl OAuth2TokenSource {
+    pub(crate) fn new(
+        transport: Arc<dyn Transport>,
+        token_url: String,
+        client_id: String,
+        client_secret: String,
+        scopes: Vec<String>,
+    ) -> Self {
+        Self {
+            transport,
+            token_url,
+            client_id,
+            client_secret,
+            scopes,
+            cached: tokio::sync::Mutex::new(None),
+            stale: AtomicBool::new(false),
+        }
+    }
+
+    /// Return a valid access token, fetching a new one if none is cached or the
+    /// cached one is about to expire.
+    async fn current(&self) -> Result<String, IgrisError> {
+        let mut cached = self.cached.lock().await;
+        if self.stale.swap(false, Ordering::SeqCst) {
+            *cached = None;
+        }
+        if let Some(token) = cached.as_ref() {
+            if Instant::now() < token.refresh_at {
+                return Ok(token.value.clone());
+            }
+        }
+
+        let fetched = self.fetch().await?;
+        let value = fetched.access_token.clone();
+        *cached = Some(CachedToken {
+            value: fetched.access_token,
+            refresh_at: Instant::now()
+                + fetched
+                    .expires_in
+                    .map_or(DEFAULT_TOKEN_LIFETIME, Duration::from_secs)
+                    .saturating_sub(REFRESH_MARGIN),
+        });
+        Ok(value)
+    }
+
+    async fn fetch(&self) -> Result<TokenResponse, IgrisError> {
+        let mut form = vec![
+            ("grant_type", "client_credentials".to_string()),
+            ("client_id", self.client_id.clone()),
+            ("client_secret", self.client_secret.clone()),
+        ];
+        if !self.scopes.is_empty() {
+            form.push(("scope", self.scopes.join(" ")));
+        }
+        let body = form
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, form_encode(v)))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/x-www-form-urlencoded"));
+        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+
+        let resp = self
+            .transport
+            .execute(TransportRequest {
+                method: reqwest::Method::POST,
+                url: self.token_url.clone(),
+                headers,
+                body: Some(body.into_bytes()),
+            })
+            .await
+            .map_err(|e| IgrisError::Auth { message: format!("token request failed: {}", e) })?;
+
+        if resp.status >= 400 {
+            return Err(IgrisError::Auth {
+                message: format!(
+                    "token endpoint returned {}: {}",
+                    resp.status,
+                    String::from_utf8_lossy(&resp.body)
+                ),
+            });
+        }
+        serde_json::from_slice(&resp.body)
+            .map_err(|e| IgrisError::Auth { message: format!("invalid token response: {}", e) })
+    }
+}
+
+impl CredentialProvider for OAuth2TokenSource {
+    fn token(&self) -> CredentialFuture<'_> {
+        Box::pin(self.current())
+    }
+
+    fn invalidate(&self) {
+        self.stale.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Percent-encode a value for `application/x-www-form-urlencoded`.
+fn form_encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char)
+            }
+            b' ' => out.push('+'),
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mask() {
+        assert_eq!(mask("sk-live-0123456789abcdef"), "...cdef");
+        assert_eq!(mask("short-key"), "[REDACTED]");
+        assert_eq!(mask(""), "[REDACTED]");
+    }
+
+    #[test]
+    fn test_form_encode() {
+        assert_eq!(form_encode("abc-1.2_~"), "abc-1.2_~");
+        assert_eq!(form_encode("read write"), "read+write");
+        assert_eq!(form_encode("a&b=c/d"), "a%26b%3Dc%2Fd");
+    }
+}
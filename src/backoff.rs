@@ -0,0 +1,137 @@
+//! Exponential backoff, as used by [`IgrisClient::wait_until_ready`].
+//!
+//! ```rust,ignore
+//! let mut backoff = Backoff::new(Duration::from_millis(100), Duration::from_secs(5)).with_jitter();
+//! while !done().await {
+//!     tokio::time::sleep(backoff.next_delay()).await;
+//! }
+//! ```
+//!
+//! [`IgrisClient::wait_until_ready`]: crate::IgrisClient::wait_until_ready
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Doubling delay sequence starting at `base` and capped at `max`.
+///
+/// With jitter enabled each delay is drawn uniformly from the upper half of the
+/// un-jittered delay (`[d/2, d]`), so clients polling in lockstep spread out
+/// while delays still grow.
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    base: Duration,
+    max: Duration,
+    current: Duration,
+    jitter: bool,
+    rng: u64,
+}
+
+impl Backoff {
+    pub fn new(base: Duration, max: Duration) -> Self {
+        Self {
+            base,
+            max,
+            current: base.min(max),
+            jitter: false,
+            rng: seed(),
+        }
+    }
+
+    /// Randomise each delay within `[d/2, d]`.
+    pub fn with_jitter(mut self) -> Self {
+        self.jitter = true;
+        self
+    }
+
+    /// Return the next delay and advance the sequence.
+    pub fn next_delay(&mut self) -> Duration {
+        let delay = self.current;
+        self.current = self.current.saturating_mul(2).min(self.max);
+        if !self.jitter {
+            return delay;
+        }
+        let half = delay / 2;
+        let spread = (delay - half).as_nanos() as u64;
+        half + Duration::from_nanos(if spread == 0 {
+            0
+        } else {
+            self.next_random() % (spread + 1)
+        })
+    }
+
+    /// Start again from `base`, e.g. after a successful attempt.
+    pub fn reset(&mut self) {
+        self.current = self.base.min(self.max);
+    }
+
+    /// xorshift64: plenty for spreading out retries, no extra dependency.
+    fn next_random(&mut self) -> u64 {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        self.rng
+    }
+}
+
+impl Iterator for Backoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        Some(self.next_delay())
+    }
+}
+
+fn seed() -> u64 {
+    // RandomState is randomly keyed per process; `| 1` keeps xorshift off zero.
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(0);
+    hasher.finish() | 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MS: Duration = Duration::from_millis(1);
+
+    #[test]
+    fn test_delays_double_until_max() {
+        let delays: Vec<_> = Backoff::new(100 * MS, 1000 * MS).take(6).collect();
+        assert_eq!(
+            delays,
+            [100 * MS, 200 * MS, 400 * MS, 800 * MS, 1000 * MS, 1000 * MS]
+        );
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut backoff = Backoff::new(100 * MS, 1000 * MS);
+        backoff.next_delay();
+        backoff.next_delay();
+        backoff.reset();
+        assert_eq!(backoff.next_delay(), 100 * MS);
+    }
+
+    #[test]
+    fn test_jitter_within_bounds() {
+        let mut plain = Backoff::new(100 * MS, 1000 * MS);
+        let mut jittered = Backoff::new(100 * MS, 1000 * MS).with_jitter();
+        for _ in 0..50 {
+            let ceiling = plain.next_delay();
+            let delay = jittered.next_delay();
+            assert!(
+                delay >= ceiling / 2 && delay <= ceiling,
+                "{:?} not in [{:?}/2, {:?}]",
+                delay,
+                ceiling,
+                ceiling
+            );
+        }
+    }
+
+    #[test]
+    fn test_base_above_max_is_capped() {
+        assert_eq!(Backoff::new(5000 * MS, 1000 * MS).next_delay(), 1000 * MS);
+    }
+}
@@ -1,43 +1,259 @@
 //! Main Igris Inertial client.
 
-use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+use std::sync::Arc;
 
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT_LANGUAGE, AUTHORIZATION, CONTENT_TYPE};
+
+use crate::auth::{self, AuthMethod, CredentialProvider, OAuth2TokenSource, StaticToken};
+use crate::backoff::Backoff;
 use crate::errors::IgrisError;
 use crate::fleet::FleetManager;
+use crate::hooks::{self, RequestHook, RequestInfo, ResponseHook, ResponseInfo};
 use crate::providers::ProviderManager;
+#[cfg(feature = "signing")]
+use crate::signing::{RequestSigner, SigningAlgorithm};
+#[cfg(feature = "metrics")]
+use crate::telemetry;
+use crate::transport::{ReqwestTransport, Transport, TransportRequest, TransportResponse};
 use crate::types::*;
 use crate::usage::{AuditManager, UsageManager};
 use crate::vault::VaultManager;
 
 /// Client for the Igris Inertial AI inference gateway.
+///
+/// `IgrisClient` is `Send + Sync` and cheap to clone: clones share the same
+/// transport, and therefore the same connection pool, credential cache and
+/// hooks. Build one client at startup and clone it into each task rather than
+/// building a new client per task.
+#[derive(Clone)]
 pub struct IgrisClient {
-    http: reqwest::Client,
+    transport: Arc<dyn Transport>,
+    default_headers: HeaderMap,
+    request_hooks: Vec<RequestHook>,
+    response_hooks: Vec<ResponseHook>,
     base_url: String,
     api_key: Option<String>,
+    credentials: Option<Arc<dyn CredentialProvider>>,
     #[allow(dead_code)]
     tenant_id: Option<String>,
+    deadline: Option<std::time::Instant>,
+    allow_insecure: bool,
+    decoder: ResponseDecoder,
+    #[cfg(feature = "signing")]
+    signer: Option<RequestSigner>,
+}
+
+impl TryFrom<&str> for IgrisClient {
+    type Error = IgrisError;
+
+    /// Equivalent to [`IgrisClient::from_url`].
+    fn try_from(connection_string: &str) -> Result<Self, IgrisError> {
+        Self::from_url(connection_string)
+    }
+}
+
+impl std::fmt::Debug for IgrisClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IgrisClient")
+            .field("base_url", &self.base_url)
+            .field("api_key", &self.api_key.as_deref().map(auth::mask))
+            .field("tenant_id", &self.tenant_id)
+            .field("deadline", &self.deadline)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Per-call overrides accepted by the `*_with_options` methods.
+///
+/// Options apply to a single request and are merged over the client's
+/// defaults; the client itself is left unchanged.
+///
+/// ```rust,ignore
+/// let mut headers = HeaderMap::new();
+/// headers.insert("x-route", HeaderValue::from_static("canary"));
+/// let options = RequestOptions {
+///     base_url_override: Some("https://canary.igris-inertial.com".into()),
+///     headers,
+///     timeout: Some(Duration::from_secs(5)),
+/// };
+/// client.infer_with_options(&request, &options).await?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    /// Send the request to this base URL instead of the client's. Subject to
+    /// the same validation as the builder's base URL.
+    pub base_url_override: Option<String>,
+    /// Extra headers, replacing any client default with the same name.
+    pub headers: HeaderMap,
+    /// Timeout for this call. It can shorten, but not extend, the client's
+    /// configured timeout.
+    pub timeout: Option<std::time::Duration>,
 }
 
 /// Builder for configuring an IgrisClient.
 pub struct IgrisClientBuilder {
     base_url: String,
-    api_key: Option<String>,
+    auth: Option<AuthMethod>,
     timeout: std::time::Duration,
     tenant_id: Option<String>,
+    accept_language: Option<String>,
+    transport: Option<Arc<dyn Transport>>,
+    request_hooks: Vec<RequestHook>,
+    response_hooks: Vec<ResponseHook>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<std::time::Duration>,
+    tcp_keepalive: Option<std::time::Duration>,
+    http_version: HttpVersion,
+    allow_insecure: bool,
+    max_response_bytes: Option<usize>,
+    decoder: ResponseDecoder,
+    #[cfg(feature = "signing")]
+    signer: Option<RequestSigner>,
+}
+
+/// Default number of response body bytes kept in [`IgrisError::InvalidResponse`].
+const DEFAULT_ERROR_BODY_LIMIT: usize = 512;
+
+/// Turns success bodies into typed values. Shared by [`IgrisClient`] and
+/// [`Runtime`](crate::Runtime) so decode failures look the same everywhere.
+#[derive(Debug, Clone)]
+pub(crate) struct ResponseDecoder {
+    error_body_limit: usize,
+    strict_parsing: bool,
+    #[cfg(feature = "tracing")]
+    log_invalid_responses: bool,
+}
+
+impl Default for ResponseDecoder {
+    fn default() -> Self {
+        Self {
+            error_body_limit: DEFAULT_ERROR_BODY_LIMIT,
+            strict_parsing: false,
+            #[cfg(feature = "tracing")]
+            log_invalid_responses: false,
+        }
+    }
+}
+
+impl ResponseDecoder {
+    /// Decode a success body. An empty body (e.g. `204 No Content`) is read as
+    /// JSON `null`, so it decodes into `()`, `Option<T>` or `Value::Null`.
+    pub(crate) fn decode<T: serde::de::DeserializeOwned>(&self, body: &[u8]) -> Result<T, IgrisError> {
+        let json: &[u8] = if body.iter().all(u8::is_ascii_whitespace) { b"null" } else { body };
+        let result = if self.strict_parsing {
+            let mut unknown = Vec::new();
+            let mut de = serde_json::Deserializer::from_slice(json);
+            serde_ignored::deserialize(&mut de, |path| unknown.push(path.to_string()))
+                .and_then(|data| de.end().map(|_| data))
+                .and_then(|data| {
+                    if unknown.is_empty() {
+                        Ok(data)
+                    } else {
+                        Err(serde::de::Error::custom(format!("unknown fields: {}", unknown.join(", "))))
+                    }
+                })
+        } else {
+            serde_json::from_slice(json)
+        };
+        result.map_err(|source| {
+            let type_name = std::any::type_name::<T>();
+            #[cfg(feature = "tracing")]
+            if self.log_invalid_responses {
+                tracing::debug!(
+                    type_name,
+                    body = %String::from_utf8_lossy(body),
+                    "response did not match expected type"
+                );
+            }
+            IgrisError::InvalidResponse {
+                type_name,
+                body: truncate_body(body, self.error_body_limit),
+                source,
+            }
+        })
+    }
+}
+
+impl std::fmt::Debug for IgrisClientBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IgrisClientBuilder")
+            .field("base_url", &self.base_url)
+            .field("auth", &self.auth)
+            .field("timeout", &self.timeout)
+            .field("tenant_id", &self.tenant_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// HTTP protocol selection for the default transport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HttpVersion {
+    /// Negotiate via ALPN: HTTP/2 over TLS when the server offers it.
+    Auto,
+    Http1Only,
+    Http2PriorKnowledge,
+}
+
+impl HttpVersion {
+    /// Turn `mode` on, or off if it is the current mode.
+    fn set(self, mode: HttpVersion, enabled: bool) -> HttpVersion {
+        match (enabled, self == mode) {
+            (true, _) => mode,
+            (false, true) => HttpVersion::Auto,
+            (false, false) => self,
+        }
+    }
 }
 
 impl IgrisClientBuilder {
     pub fn new(base_url: impl Into<String>) -> Self {
         Self {
             base_url: base_url.into(),
-            api_key: None,
+            auth: None,
             timeout: std::time::Duration::from_secs(30),
             tenant_id: None,
+            accept_language: None,
+            transport: None,
+            request_hooks: Vec::new(),
+            response_hooks: Vec::new(),
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            tcp_keepalive: None,
+            http_version: HttpVersion::Auto,
+            allow_insecure: false,
+            max_response_bytes: None,
+            decoder: ResponseDecoder::default(),
+            #[cfg(feature = "signing")]
+            signer: None,
         }
     }
 
     pub fn api_key(mut self, key: impl Into<String>) -> Self {
-        self.api_key = Some(key.into());
+        self.auth = Some(AuthMethod::ApiKey(key.into()));
+        self
+    }
+
+    /// Set the authentication method. Replaces any earlier `api_key`, `oauth2`
+    /// or `credentials` call.
+    pub fn auth(mut self, auth: AuthMethod) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    /// Authenticate with the OAuth2 client-credentials grant instead of an API key.
+    pub fn oauth2(
+        mut self,
+        token_url: impl Into<String>,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        scopes: Vec<String>,
+    ) -> Self {
+        self.auth = Some(AuthMethod::OAuth2 {
+            token_url: token_url.into(),
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            scopes,
+        });
         self
     }
 
@@ -51,15 +267,184 @@ impl IgrisClientBuilder {
         self
     }
 
+    /// Send `Accept-Language` with every request, e.g. `"de-DE"`.
+    ///
+    /// The gateway may then localize error messages, so the `message` of
+    /// [`IgrisError::Api`] and friends should not be matched on. `build` fails
+    /// with [`IgrisError::Config`] if `tag` is not a well-formed language tag.
+    pub fn accept_language(mut self, tag: impl Into<String>) -> Self {
+        self.accept_language = Some(tag.into());
+        self
+    }
+
+    /// Maximum idle connections kept per host (default: unlimited).
+    ///
+    /// Keep this at or above the number of concurrent requests you issue so
+    /// connections are reused instead of re-established under load.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// How long an idle pooled connection is kept before closing (default: 90s).
+    pub fn pool_idle_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Idle time before TCP keep-alive probes start (default: 15s).
+    pub fn tcp_keepalive(mut self, idle: std::time::Duration) -> Self {
+        self.tcp_keepalive = Some(idle);
+        self
+    }
+
+    /// Speak HTTP/2 without negotiation, including over plain `http://`.
+    ///
+    /// HTTP/2 multiplexes concurrent requests over one connection, which cuts
+    /// latency for high-QPS inference. Only enable it when every server (and
+    /// proxy) in front of the gateway accepts HTTP/2 directly; otherwise
+    /// requests fail to connect. By default the protocol is negotiated via
+    /// ALPN, which already selects HTTP/2 over TLS when the server offers it.
+    /// Overrides [`IgrisClientBuilder::http1_only`]; passing `false` only
+    /// turns off prior knowledge and leaves an HTTP/1-only setting alone.
+    pub fn http2_prior_knowledge(mut self, enabled: bool) -> Self {
+        self.http_version = self.http_version.set(HttpVersion::Http2PriorKnowledge, enabled);
+        self
+    }
+
+    /// Restrict the client to HTTP/1.1, e.g. behind proxies that mishandle
+    /// HTTP/2. Overrides [`IgrisClientBuilder::http2_prior_knowledge`];
+    /// passing `false` only turns off HTTP/1-only mode.
+    pub fn http1_only(mut self, enabled: bool) -> Self {
+        self.http_version = self.http_version.set(HttpVersion::Http1Only, enabled);
+        self
+    }
+
+    /// Permit a plain `http://` base URL for non-loopback hosts.
+    ///
+    /// By default `build` rejects such URLs because the API key would be sent
+    /// in cleartext. Loopback hosts (`localhost`, `127.0.0.0/8`, `::1`) are
+    /// always allowed.
+    pub fn allow_insecure(mut self, allow: bool) -> Self {
+        self.allow_insecure = allow;
+        self
+    }
+
+    /// Refuse to read success bodies larger than `limit` bytes, failing with
+    /// [`IgrisError::ResponseTooLarge`] instead. Error bodies are truncated to
+    /// `limit` bytes so the usual status error is still returned. Unlimited by
+    /// default.
+    ///
+    /// Applies to the default transport only; a custom [`Transport`] enforces
+    /// its own limits (see [`ReqwestTransport::max_response_bytes`]).
+    pub fn max_response_bytes(mut self, limit: usize) -> Self {
+        self.max_response_bytes = Some(limit);
+        self
+    }
+
+    /// Maximum number of response body bytes included in
+    /// [`IgrisError::InvalidResponse`] (default 512).
+    pub fn error_body_limit(mut self, bytes: usize) -> Self {
+        self.decoder.error_body_limit = bytes;
+        self
+    }
+
+    /// Reject responses containing fields the SDK's types do not know about.
+    ///
+    /// Off by default so newer servers stay compatible with older SDKs. Turn it
+    /// on in development or CI to catch schema drift: unknown fields then fail
+    /// with [`IgrisError::InvalidResponse`] naming each field's path.
+    pub fn strict_parsing(mut self, enabled: bool) -> Self {
+        self.decoder.strict_parsing = enabled;
+        self
+    }
+
+    /// Log the full body of responses that fail to decode, at debug level.
+    /// Bodies may contain sensitive data, so this is off by default.
+    #[cfg(feature = "tracing")]
+    pub fn log_invalid_responses(mut self, enabled: bool) -> Self {
+        self.decoder.log_invalid_responses = enabled;
+        self
+    }
+
+    /// Sign every request with an HMAC of its method, path, body and a
+    /// timestamp, sent as `X-Signature` and `X-Timestamp` alongside the usual
+    /// credentials. See [`crate::signing`] for the canonical string.
+    #[cfg(feature = "signing")]
+    pub fn request_signing(mut self, secret: impl Into<Vec<u8>>, algorithm: SigningAlgorithm) -> Self {
+        self.signer = Some(RequestSigner::new(secret, algorithm));
+        self
+    }
+
+    /// Obtain bearer tokens from a custom [`CredentialProvider`].
+    pub fn credentials(mut self, provider: impl CredentialProvider + 'static) -> Self {
+        self.auth = Some(AuthMethod::Custom(Arc::new(provider)));
+        self
+    }
+
+    /// Route all requests through a custom [`Transport`] instead of the default
+    /// `reqwest` client. See [`crate::transport`] for details.
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Register a callback run before every request. May be called repeatedly.
+    pub fn on_request(mut self, hook: RequestHook) -> Self {
+        self.request_hooks.push(hook);
+        self
+    }
+
+    /// Register a callback run after every response. May be called repeatedly.
+    pub fn on_response(mut self, hook: ResponseHook) -> Self {
+        self.response_hooks.push(hook);
+        self
+    }
+
     pub fn build(self) -> Result<IgrisClient, IgrisError> {
+        let base_url = normalize_base_url(&self.base_url, self.allow_insecure)?;
+        let transport = match self.transport {
+            Some(ref transport) => transport.clone(),
+            None => {
+                let mut transport = ReqwestTransport::new(self.build_http_client()?);
+                if let Some(limit) = self.max_response_bytes {
+                    transport = transport.max_response_bytes(limit);
+                }
+                Arc::new(transport)
+            }
+        };
+
+        let mut api_key = None;
+        let credentials: Option<Arc<dyn CredentialProvider>> = match &self.auth {
+            Some(AuthMethod::ApiKey(key)) => {
+                bearer_header(key).map_err(|e| IgrisError::Config {
+                    message: format!("API key is not a valid header value: {}", e),
+                })?;
+                api_key = Some(key.clone());
+                Some(Arc::new(StaticToken::new(key.clone())))
+            }
+            Some(AuthMethod::OAuth2 { token_url, client_id, client_secret, scopes }) => {
+                check_url(token_url, self.allow_insecure)?;
+                Some(Arc::new(OAuth2TokenSource::new(
+                    transport.clone(),
+                    token_url.clone(),
+                    client_id.clone(),
+                    client_secret.clone(),
+                    scopes.clone(),
+                )))
+            }
+            Some(AuthMethod::Custom(provider)) => Some(provider.clone()),
+            None => None,
+        };
+
         let mut headers = HeaderMap::new();
-        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-        if let Some(ref key) = self.api_key {
-            headers.insert(
-                AUTHORIZATION,
-                HeaderValue::from_str(&format!("Bearer {}", key))
-                    .map_err(|e| IgrisError::Api { message: e.to_string(), status_code: 0 })?,
-            );
+        if let Some(ref tag) = self.accept_language {
+            if !is_language_tag(tag) {
+                return Err(IgrisError::Config {
+                    message: format!("invalid Accept-Language tag {:?}", tag),
+                });
+            }
+            headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_str(tag).expect("validated tag"));
         }
         if let Some(ref tid) = self.tenant_id {
             headers.insert(
@@ -69,18 +454,145 @@ impl IgrisClientBuilder {
             );
         }
 
-        let http = reqwest::Client::builder()
-            .default_headers(headers)
-            .timeout(self.timeout)
-            .build()?;
-
         Ok(IgrisClient {
-            http,
-            base_url: self.base_url.trim_end_matches('/').to_string(),
-            api_key: self.api_key,
+            transport,
+            default_headers: headers,
+            request_hooks: self.request_hooks,
+            response_hooks: self.response_hooks,
+            base_url,
+            api_key,
+            credentials,
             tenant_id: self.tenant_id,
+            deadline: None,
+            allow_insecure: self.allow_insecure,
+            decoder: self.decoder,
+            #[cfg(feature = "signing")]
+            signer: self.signer,
         })
     }
+
+    /// Build the default `reqwest` client. Connection settings only apply here;
+    /// a custom [`Transport`] manages its own connections.
+    fn build_http_client(&self) -> Result<reqwest::Client, IgrisError> {
+        let mut builder = reqwest::Client::builder().timeout(self.timeout);
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if let Some(idle) = self.tcp_keepalive {
+            builder = builder.tcp_keepalive(idle);
+        }
+        builder = match self.http_version {
+            HttpVersion::Auto => builder,
+            HttpVersion::Http1Only => builder.http1_only(),
+            HttpVersion::Http2PriorKnowledge => builder.http2_prior_knowledge(),
+        };
+        Ok(builder.build()?)
+    }
+}
+
+fn bearer_header(token: &str) -> Result<HeaderValue, reqwest::header::InvalidHeaderValue> {
+    let mut value = HeaderValue::from_str(&format!("Bearer {}", token))?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+/// Decode `%XX` escapes, returning `None` if the result is not UTF-8.
+fn percent_decode(input: &str) -> Option<String> {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .filter(|_| bytes[i] == b'%')
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                out.push(byte);
+                i += 3;
+            }
+            None => {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(out).ok()
+}
+
+/// Loose BCP 47 check: a 2-8 letter primary subtag followed by `-`-separated
+/// alphanumeric subtags of 1-8 characters.
+fn is_language_tag(tag: &str) -> bool {
+    let mut subtags = tag.split('-');
+    let primary = subtags.next().unwrap_or_default();
+    (2..=8).contains(&primary.len())
+        && primary.bytes().all(|b| b.is_ascii_alphabetic())
+        && subtags.all(|s| (1..=8).contains(&s.len()) && s.bytes().all(|b| b.is_ascii_alphanumeric()))
+}
+
+/// Lossily decode at most `limit` bytes of `body`, marking any truncation.
+fn truncate_body(body: &[u8], limit: usize) -> String {
+    if body.len() <= limit {
+        return String::from_utf8_lossy(body).into_owned();
+    }
+    let mut text = String::from_utf8_lossy(&body[..limit]).into_owned();
+    // A multi-byte character cut at the limit decodes as U+FFFD; drop it.
+    if text.ends_with('\u{FFFD}') {
+        text.pop();
+    }
+    text.push_str("...");
+    text
+}
+
+/// Validate `base_url` and strip trailing slashes so joined paths never
+/// contain `//`.
+fn normalize_base_url(base_url: &str, allow_insecure: bool) -> Result<String, IgrisError> {
+    let trimmed = base_url.trim().trim_end_matches('/');
+    let parsed = check_url(trimmed, allow_insecure)?;
+    if parsed.query().is_some() || parsed.fragment().is_some() {
+        return Err(IgrisError::Config {
+            message: format!("base URL {:?} must not contain a query or fragment", base_url),
+        });
+    }
+    Ok(trimmed.to_string())
+}
+
+/// Parse an endpoint URL, requiring http(s) with a host. Cleartext `http` is
+/// only accepted for loopback hosts unless `allow_insecure` is set.
+fn check_url(url: &str, allow_insecure: bool) -> Result<reqwest::Url, IgrisError> {
+    let parsed = reqwest::Url::parse(url.trim()).map_err(|e| IgrisError::Config {
+        message: format!("invalid URL {:?}: {}", url, e),
+    })?;
+    if !matches!(parsed.scheme(), "http" | "https") || parsed.host_str().is_none() {
+        return Err(IgrisError::Config {
+            message: format!("URL {:?} must be an http(s) URL with a host", url),
+        });
+    }
+    if parsed.scheme() == "http" && !allow_insecure && !is_loopback(&parsed) {
+        return Err(IgrisError::Config {
+            message: format!(
+                "refusing insecure URL {:?}; use https or set allow_insecure(true)",
+                url
+            ),
+        });
+    }
+    Ok(parsed)
+}
+
+fn is_loopback(url: &reqwest::Url) -> bool {
+    match url.host_str() {
+        Some(host) if host.eq_ignore_ascii_case("localhost") => true,
+        Some(host) => host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback()),
+        None => false,
+    }
 }
 
 impl IgrisClient {
@@ -94,42 +606,132 @@ impl IgrisClient {
         Self::builder(base_url).api_key(api_key).build()
     }
 
+    /// Build a client from a connection string such as
+    /// `igris://API_KEY@api.igris-inertial.com/base/path`.
+    ///
+    /// `igris://` connects over https; `igris+http://` uses plain http and is
+    /// subject to the same loopback-only rule as [`IgrisClientBuilder::allow_insecure`].
+    /// The API key is the URL's user part and may be percent-encoded. Returns
+    /// [`IgrisError::Config`] for malformed strings or a missing API key.
+    pub fn from_url(connection_string: &str) -> Result<Self, IgrisError> {
+        let config_err = |message: String| IgrisError::Config { message };
+        let parsed = reqwest::Url::parse(connection_string.trim())
+            .map_err(|e| config_err(format!("invalid connection string: {}", e)))?;
+        let scheme = match parsed.scheme() {
+            "igris" => "https",
+            "igris+http" => "http",
+            other => {
+                return Err(config_err(format!(
+                    "unsupported connection string scheme {:?}; expected igris:// or igris+http://",
+                    other
+                )))
+            }
+        };
+        if parsed.password().is_some() {
+            return Err(config_err("connection string must not contain a password".into()));
+        }
+        let api_key = percent_decode(parsed.username())
+            .ok_or_else(|| config_err("connection string API key is not valid UTF-8".into()))?;
+        if api_key.is_empty() {
+            return Err(config_err("connection string has no API key".into()));
+        }
+        let host = parsed
+            .host_str()
+            .filter(|h| !h.is_empty())
+            .ok_or_else(|| config_err("connection string has no host".into()))?;
+        let port = parsed.port().map(|p| format!(":{}", p)).unwrap_or_default();
+        let mut base_url = format!("{}://{}{}{}", scheme, host, port, parsed.path());
+        // A query or fragment is passed through so the base URL validation
+        // rejects it.
+        if let Some(query) = parsed.query() {
+            base_url = format!("{}?{}", base_url, query);
+        }
+        if let Some(fragment) = parsed.fragment() {
+            base_url = format!("{}#{}", base_url, fragment);
+        }
+        Self::new(base_url, api_key)
+    }
+
+    /// Constructor using the OAuth2 client-credentials grant.
+    pub fn with_oauth2(
+        base_url: impl Into<String>,
+        token_url: impl Into<String>,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+    ) -> Result<Self, IgrisError> {
+        Self::builder(base_url).oauth2(token_url, client_id, client_secret, Vec::new()).build()
+    }
+
+    /// Return a handle whose calls all share one overall `deadline`.
+    ///
+    /// Each request made through the handle is given the time remaining until
+    /// `deadline` as its timeout (capped by the client's own timeout), and
+    /// fails with [`IgrisError::Timeout`] once the deadline has passed. This is
+    /// useful for bounding a chain of dependent calls as a whole. The handle
+    /// shares the original client's transport and configuration.
+    ///
+    /// ```rust,ignore
+    /// let scoped = client.with_deadline(Instant::now() + Duration::from_secs(5));
+    /// scoped.vault().rotate("openai").await?;
+    /// scoped.infer(&request).await?; // fails fast if the rotation used up the budget
+    /// ```
+    pub fn with_deadline(&self, deadline: std::time::Instant) -> IgrisClient {
+        IgrisClient { deadline: Some(deadline), ..self.clone() }
+    }
+
     pub(crate) fn url(&self, path: &str) -> String {
         format!("{}{}", self.base_url, path)
     }
 
-    pub(crate) async fn request<T: serde::de::DeserializeOwned>(
+    /// Call an arbitrary gateway endpoint and decode the JSON response.
+    ///
+    /// This is an escape hatch for endpoints the SDK does not wrap yet. It uses
+    /// the same authentication, hooks and error mapping as the typed methods,
+    /// but it is not covered by semver: prefer a typed method once one exists.
+    ///
+    /// ```rust,ignore
+    /// let stats: serde_json::Value = client
+    ///     .request(reqwest::Method::GET, "/v1/new-endpoint", None::<&()>)
+    ///     .await?;
+    /// ```
+    pub async fn request<T: serde::de::DeserializeOwned>(
         &self,
         method: reqwest::Method,
         path: &str,
         body: Option<&impl serde::Serialize>,
     ) -> Result<T, IgrisError> {
-        let mut req = self.http.request(method, self.url(path));
-        if let Some(b) = body {
-            req = req.json(b);
-        }
-        let resp = req.send().await?;
-        let status = resp.status().as_u16();
+        self.request_with_options(method, path, body, &RequestOptions::default()).await
+    }
 
-        if status == 401 || status == 403 {
-            let text = resp.text().await.unwrap_or_default();
-            return Err(IgrisError::Authentication { message: text, status_code: status });
-        }
-        if status == 429 {
-            let text = resp.text().await.unwrap_or_default();
-            return Err(IgrisError::RateLimit { message: text });
-        }
-        if status == 400 || status == 422 {
-            let text = resp.text().await.unwrap_or_default();
-            return Err(IgrisError::Validation { message: text, status_code: status });
-        }
-        if status >= 400 {
-            let text = resp.text().await.unwrap_or_default();
-            return Err(IgrisError::Api { message: text, status_code: status });
+    /// Like [`IgrisClient::request`], with per-call [`RequestOptions`].
+    pub async fn request_with_options<T: serde::de::DeserializeOwned>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&impl serde::Serialize>,
+        options: &RequestOptions,
+    ) -> Result<T, IgrisError> {
+        let operation = format!("{} {}", method, path);
+        async {
+            let resp = self.send(method, path, body, options).await?;
+            self.decoder.decode(&resp.body)
         }
+        .await
+        .map_err(|e: IgrisError| e.with_context(operation))
+    }
 
-        let data = resp.json().await?;
-        Ok(data)
+    /// Like [`IgrisClient::request`], but return the raw response body.
+    pub async fn request_raw(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&impl serde::Serialize>,
+    ) -> Result<Vec<u8>, IgrisError> {
+        let operation = format!("{} {}", method, path);
+        self.send(method, path, body, &RequestOptions::default())
+            .await
+            .map(|resp| resp.body)
+            .map_err(|e| e.with_context(operation))
     }
 
     pub(crate) async fn request_no_body(
@@ -137,18 +739,11 @@ impl IgrisClient {
         method: reqwest::Method,
         path: &str,
     ) -> Result<(), IgrisError> {
-        let resp = self.http.request(method, self.url(path)).send().await?;
-        let status = resp.status().as_u16();
-
-        if status == 401 || status == 403 {
-            let text = resp.text().await.unwrap_or_default();
-            return Err(IgrisError::Authentication { message: text, status_code: status });
-        }
-        if status >= 400 {
-            let text = resp.text().await.unwrap_or_default();
-            return Err(IgrisError::Api { message: text, status_code: status });
-        }
-        Ok(())
+        let operation = format!("{} {}", method, path);
+        self.send(method, path, None::<&()>, &RequestOptions::default())
+            .await
+            .map(|_| ())
+            .map_err(|e| e.with_context(operation))
     }
 
     pub(crate) async fn send_json_no_response(
@@ -157,20 +752,230 @@ impl IgrisClient {
         path: &str,
         body: &impl serde::Serialize,
     ) -> Result<(), IgrisError> {
-        let resp = self.http.request(method, self.url(path)).json(body).send().await?;
-        let status = resp.status().as_u16();
+        let operation = format!("{} {}", method, path);
+        self.send(method, path, Some(body), &RequestOptions::default())
+            .await
+            .map(|_| ())
+            .map_err(|e| e.with_context(operation))
+    }
+
+    /// Send a request and map non-success statuses to typed errors.
+    async fn send(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&impl serde::Serialize>,
+        options: &RequestOptions,
+    ) -> Result<TransportResponse, IgrisError> {
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::Instrument;
+
+            let span = tracing::info_span!(
+                "igris.request",
+                http.method = %method,
+                http.path = path,
+                http.status_code = tracing::field::Empty,
+                elapsed_ms = tracing::field::Empty,
+                request_id = tracing::field::Empty,
+                error = tracing::field::Empty,
+            );
+            let started = std::time::Instant::now();
+            let result = self.dispatch(method, path, body, options).instrument(span.clone()).await;
+            span.record("elapsed_ms", started.elapsed().as_millis() as u64);
+            match &result {
+                Ok(resp) => {
+                    span.record("http.status_code", resp.status);
+                    if let Some(id) = resp.headers.get("x-request-id").and_then(|v| v.to_str().ok()) {
+                        span.record("request_id", id);
+                    }
+                }
+                Err(e) => {
+                    if let Some(status) = e.status_code() {
+                        span.record("http.status_code", status);
+                    }
+                    span.record("error", tracing::field::display(e));
+                }
+            }
+            span.in_scope(|| tracing::debug!("request completed"));
+            result
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        self.dispatch(method, path, body, options).await
+    }
+
+    async fn dispatch(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&impl serde::Serialize>,
+        options: &RequestOptions,
+    ) -> Result<TransportResponse, IgrisError> {
+        let url = match &options.base_url_override {
+            Some(base) => format!("{}{}", normalize_base_url(base, self.allow_insecure)?, path),
+            None => self.url(path),
+        };
+        // The per-call timeout covers the whole call, token fetch included.
+        let timeout = options.timeout.map(|t| (std::time::Instant::now() + t, t));
+        let mut headers = self.default_headers.clone();
+        if let Some(credentials) = &self.credentials {
+            self.check_deadline(&method, path)?;
+            let operation = format!("fetching credentials for {} {}", method, path);
+            let token = self.bounded(&operation, timeout, credentials.token()).await?;
+            headers.insert(
+                AUTHORIZATION,
+                bearer_header(&token).map_err(|e| IgrisError::Auth {
+                    message: format!("token is not a valid header value: {}", e),
+                })?,
+            );
+        }
+        for (name, value) in &options.headers {
+            headers.insert(name, value.clone());
+        }
+        let body = match body {
+            Some(b) => {
+                headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+                Some(serde_json::to_vec(b)?)
+            }
+            None => None,
+        };
+        #[cfg(feature = "signing")]
+        if let Some(signer) = &self.signer {
+            let parsed = reqwest::Url::parse(&url).map_err(|e| IgrisError::Config {
+                message: format!("invalid request URL {:?}: {}", url, e),
+            })?;
+            let target = match parsed.query() {
+                Some(query) => format!("{}?{}", parsed.path(), query),
+                None => parsed.path().to_string(),
+            };
+            let timestamp = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default();
+            let signature =
+                signer.sign(method.as_str(), &target, timestamp, body.as_deref().unwrap_or_default());
+            headers.insert("X-Timestamp", HeaderValue::from(timestamp));
+            headers.insert(
+                "X-Signature",
+                HeaderValue::from_str(&signature).expect("base64 is a valid header value"),
+            );
+        }
+        let resp = self
+            .execute(path, TransportRequest { method, url, headers, body }, timeout)
+            .await?;
+        let status = resp.status;
 
         if status == 401 || status == 403 {
-            let text = resp.text().await.unwrap_or_default();
+            if let Some(credentials) = &self.credentials {
+                credentials.invalidate();
+            }
+            let text = String::from_utf8_lossy(&resp.body).into_owned();
             return Err(IgrisError::Authentication { message: text, status_code: status });
         }
+        if status == 429 {
+            let text = String::from_utf8_lossy(&resp.body).into_owned();
+            return Err(IgrisError::RateLimit { message: text });
+        }
+        if status == 400 || status == 422 {
+            let text = String::from_utf8_lossy(&resp.body).into_owned();
+            return Err(IgrisError::Validation { message: text, status_code: status });
+        }
         if status >= 400 {
-            let text = resp.text().await.unwrap_or_default();
+            let text = String::from_utf8_lossy(&resp.body).into_owned();
             return Err(IgrisError::Api { message: text, status_code: status });
         }
+        Ok(resp)
+    }
+
+    fn check_deadline(&self, method: &reqwest::Method, path: &str) -> Result<(), IgrisError> {
+        if self.deadline.is_some_and(|d| d <= std::time::Instant::now()) {
+            return Err(IgrisError::Timeout {
+                message: format!("deadline passed before {} {} was sent", method, path),
+            });
+        }
         Ok(())
     }
 
+    /// Run `future`, giving up at the client deadline or at `timeout` (the
+    /// per-call expiry and its original duration), whichever comes first.
+    async fn bounded<T>(
+        &self,
+        operation: &str,
+        timeout: Option<(std::time::Instant, std::time::Duration)>,
+        future: impl std::future::Future<Output = Result<T, IgrisError>>,
+    ) -> Result<T, IgrisError> {
+        let deadline = self.deadline.map(|at| (at, true));
+        let per_call = timeout.map(|(at, _)| (at, false));
+        let limit = match (deadline, per_call) {
+            (Some(d), Some(c)) => Some(if d.0 <= c.0 { d } else { c }),
+            (d, c) => d.or(c),
+        };
+        let Some((limit, is_deadline)) = limit else {
+            return future.await;
+        };
+        match tokio::time::timeout_at(limit.into(), future).await {
+            Ok(result) => result,
+            Err(_) if is_deadline => Err(IgrisError::Timeout {
+                message: format!("{} did not complete before the deadline", operation),
+            }),
+            Err(_) => Err(IgrisError::Timeout {
+                message: format!(
+                    "{} did not complete within {:?}",
+                    operation,
+                    timeout.map(|(_, d)| d).unwrap_or_default()
+                ),
+            }),
+        }
+    }
+
+    /// Hand a prepared request to the transport, invoking any registered hooks.
+    ///
+    /// The call is bounded by `timeout` and by the deadline, whichever comes
+    /// first.
+    async fn execute(
+        &self,
+        path: &str,
+        request: TransportRequest,
+        timeout: Option<(std::time::Instant, std::time::Duration)>,
+    ) -> Result<TransportResponse, IgrisError> {
+        let method = request.method.clone();
+        self.check_deadline(&method, path)?;
+        if !self.request_hooks.is_empty() {
+            let info = RequestInfo {
+                method: method.clone(),
+                path: path.to_string(),
+                headers: hooks::redacted(&request.headers),
+            };
+            for hook in &self.request_hooks {
+                hook(&info);
+            }
+        }
+
+        let started = std::time::Instant::now();
+        let operation = format!("{} {}", method, path);
+        let result = self.bounded(&operation, timeout, self.transport.execute(request)).await;
+        let elapsed = started.elapsed();
+        let status = result.as_ref().ok().map(|r| r.status);
+
+        #[cfg(feature = "metrics")]
+        telemetry::record_request(method.as_str(), path, status, elapsed);
+
+        if !self.response_hooks.is_empty() {
+            let info = ResponseInfo {
+                method,
+                path: path.to_string(),
+                status,
+                elapsed,
+                headers: result.as_ref().map(|r| r.headers.clone()).unwrap_or_default(),
+            };
+            for hook in &self.response_hooks {
+                hook(&info);
+            }
+        }
+        result
+    }
+
     // ── Auth ──
 
     pub async fn login(&self, api_key: Option<&str>) -> Result<serde_json::Value, IgrisError> {
@@ -197,6 +1002,62 @@ impl IgrisClient {
         self.request(reqwest::Method::POST, "/v1/chat/completions", Some(request)).await
     }
 
+    /// Run many inference requests with at most `concurrency` in flight.
+    ///
+    /// Results are returned in the same order as `requests`, one per input, so
+    /// a failed request does not discard the others. A `concurrency` of 0 is
+    /// treated as 1. Dropping the returned future aborts any requests still in
+    /// flight.
+    pub async fn infer_many(
+        &self,
+        requests: Vec<InferRequest>,
+        concurrency: usize,
+    ) -> Vec<Result<InferResponse, IgrisError>> {
+        let total = requests.len();
+        let mut pending = requests.into_iter().enumerate();
+        let mut tasks = tokio::task::JoinSet::new();
+        let mut results: Vec<Option<Result<InferResponse, IgrisError>>> =
+            (0..total).map(|_| None).collect();
+
+        for (index, request) in pending.by_ref().take(concurrency.max(1)) {
+            let client = self.clone();
+            tasks.spawn(async move { (index, client.infer(&request).await) });
+        }
+        while let Some(joined) = tasks.join_next().await {
+            let (index, result) = match joined {
+                Ok(done) => done,
+                Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+                // Only happens while the runtime is shutting down.
+                Err(e) => panic!("infer task cancelled: {}", e),
+            };
+            results[index] = Some(result);
+            if let Some((index, request)) = pending.next() {
+                let client = self.clone();
+                tasks.spawn(async move { (index, client.infer(&request).await) });
+            }
+        }
+        results.into_iter().map(|r| r.expect("every request produces a result")).collect()
+    }
+
+    /// [`IgrisClient::infer`] with per-call [`RequestOptions`].
+    pub async fn infer_with_options(
+        &self,
+        request: &InferRequest,
+        options: &RequestOptions,
+    ) -> Result<InferResponse, IgrisError> {
+        self.request_with_options(reqwest::Method::POST, "/v1/infer", Some(request), options).await
+    }
+
+    /// [`IgrisClient::chat_completion`] with per-call [`RequestOptions`].
+    pub async fn chat_completion_with_options(
+        &self,
+        request: &InferRequest,
+        options: &RequestOptions,
+    ) -> Result<InferResponse, IgrisError> {
+        self.request_with_options(reqwest::Method::POST, "/v1/chat/completions", Some(request), options)
+            .await
+    }
+
     pub async fn list_models(&self) -> Result<ModelsResponse, IgrisError> {
         self.request::<ModelsResponse>(reqwest::Method::GET, "/v1/models", None::<&()>.as_ref()).await
     }
@@ -205,6 +1066,43 @@ impl IgrisClient {
         self.request::<HealthResponse>(reqwest::Method::GET, "/v1/health", None::<&()>.as_ref()).await
     }
 
+    /// Poll [`IgrisClient::health`] until the gateway reports `"healthy"` (or
+    /// `"ok"`), backing off between attempts.
+    ///
+    /// Failed health calls count as "not ready yet" and are retried. Returns
+    /// [`IgrisError::Timeout`] if the gateway is still not ready after `timeout`,
+    /// or by the handle's own deadline if that comes first.
+    pub async fn wait_until_ready(&self, timeout: std::time::Duration) -> Result<(), IgrisError> {
+        let limit = std::time::Instant::now() + timeout;
+        let deadline = self.deadline.map_or(limit, |d| d.min(limit));
+        let scoped = self.with_deadline(deadline);
+        let mut backoff =
+            Backoff::new(std::time::Duration::from_millis(100), std::time::Duration::from_secs(2))
+                .with_jitter();
+        loop {
+            let last = match scoped.health().await {
+                Ok(health) if health.status == "healthy" || health.status == "ok" => return Ok(()),
+                Ok(health) => format!("status {:?}", health.status),
+                Err(e) => e.to_string(),
+            };
+            let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+            if remaining.is_zero() {
+                return Err(IgrisError::Timeout {
+                    message: format!("gateway not ready before the deadline (last: {})", last),
+                });
+            }
+            tokio::time::sleep(backoff.next_delay().min(remaining)).await;
+        }
+    }
+
+    /// Cheap liveness probe: `HEAD /v1/health` without decoding a body.
+    ///
+    /// Returns `Ok(())` on any 2xx status and an error otherwise, which makes it
+    /// suitable for load balancer and sidecar checks.
+    pub async fn ping(&self) -> Result<(), IgrisError> {
+        self.request_no_body(reqwest::Method::HEAD, "/v1/health").await
+    }
+
     pub async fn provider_stats(&self) -> Result<serde_json::Value, IgrisError> {
         self.request::<serde_json::Value>(reqwest::Method::GET, "/v1/providers/stats", None::<&()>.as_ref()).await
     }
@@ -252,3 +1150,28 @@ impl IgrisClient {
         self.vault().list().await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disabling_one_http_mode_keeps_the_other() {
+        let builder = || IgrisClient::builder("http://localhost:8080");
+
+        assert_eq!(builder().http_version, HttpVersion::Auto);
+        assert_eq!(
+            builder().http1_only(true).http2_prior_knowledge(false).http_version,
+            HttpVersion::Http1Only
+        );
+        assert_eq!(
+            builder().http2_prior_knowledge(true).http1_only(false).http_version,
+            HttpVersion::Http2PriorKnowledge
+        );
+        assert_eq!(builder().http1_only(true).http1_only(false).http_version, HttpVersion::Auto);
+        assert_eq!(
+            builder().http2_prior_knowledge(true).http1_only(true).http_version,
+            HttpVersion::Http1Only
+        );
+    }
+}
@@ -8,7 +8,9 @@
 //!   [`Runtime::get_last_violation`].
 //! - **CPU violation**: Worker exceeded the cgroup CPU quota. Treated identically.
 //! - **Cloud outage**: `Runtime::chat` falls back to local when `auto_fallback` is
-//!   true; returns `Err(IgrisError::Network(_))` otherwise.
+//!   true; otherwise it returns an error wrapped in
+//!   [`IgrisError::Context`](crate::IgrisError::Context). Match on `e.root()` to
+//!   see the underlying `IgrisError::Network(_)`.
 //! - **Worker SIGKILL**: Supervisor reaps the process, destroys the cgroup, records
 //!   the violation, and spawns a fresh worker automatically.
 
@@ -7,6 +7,12 @@ pub enum IgrisError {
     #[error("Authentication failed: {message}")]
     Authentication { message: String, status_code: u16 },
 
+    /// Credentials could not be obtained, e.g. an OAuth2 token request failed.
+    /// Distinct from [`IgrisError::Authentication`], which means the gateway
+    /// rejected the credentials that were sent.
+    #[error("Credential error: {message}")]
+    Auth { message: String },
+
     #[error("Rate limit exceeded: {message}")]
     RateLimit { message: String },
 
@@ -19,6 +25,111 @@ pub enum IgrisError {
     #[error("API error ({status_code}): {message}")]
     Api { message: String, status_code: u16 },
 
+    #[error("Invalid configuration: {message}")]
+    Config { message: String },
+
+    #[error("Deadline exceeded: {message}")]
+    Timeout { message: String },
+
     #[error("Deserialization error: {0}")]
     Deserialization(#[from] serde_json::Error),
+
+    /// A response body did not match the type the SDK expected. `body` holds
+    /// the start of the raw response, truncated to the client's
+    /// `error_body_limit`.
+    #[error("Invalid response for {type_name}: {source} (body: {body})")]
+    InvalidResponse {
+        type_name: &'static str,
+        body: String,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    /// The response body exceeded the configured `max_response_bytes`.
+    #[error("Response body exceeds {limit} bytes")]
+    ResponseTooLarge { limit: usize },
+
+    /// An error annotated with the SDK operation that produced it. Request
+    /// methods return their errors in this wrapper; match on
+    /// [`IgrisError::root`] to see the underlying variant.
+    #[error("{operation} failed: {source}")]
+    Context {
+        operation: String,
+        #[source]
+        source: Box<IgrisError>,
+    },
+}
+
+impl IgrisError {
+    /// Wrap this error with the name of the operation that produced it.
+    ///
+    /// Errors that already carry context are returned unchanged so the
+    /// innermost (most specific) operation name is kept.
+    pub fn with_context(self, operation: impl Into<String>) -> Self {
+        match self {
+            IgrisError::Context { .. } => self,
+            other => IgrisError::Context {
+                operation: operation.into(),
+                source: Box::new(other),
+            },
+        }
+    }
+
+    /// The operation name attached by [`IgrisError::with_context`], if any.
+    pub fn operation(&self) -> Option<&str> {
+        match self {
+            IgrisError::Context { operation, .. } => Some(operation),
+            _ => None,
+        }
+    }
+
+    /// HTTP status code of the response that produced this error, if any.
+    pub fn status_code(&self) -> Option<u16> {
+        match self.root() {
+            IgrisError::Authentication { status_code, .. }
+            | IgrisError::Validation { status_code, .. }
+            | IgrisError::Api { status_code, .. } => Some(*status_code).filter(|c| *c != 0),
+            IgrisError::RateLimit { .. } => Some(429),
+            IgrisError::Network(e) => e.status().map(|s| s.as_u16()),
+            _ => None,
+        }
+    }
+
+    /// The server returned 404 Not Found.
+    pub fn is_not_found(&self) -> bool {
+        self.status_code() == Some(404)
+    }
+
+    /// The server returned 401 Unauthorized.
+    pub fn is_unauthorized(&self) -> bool {
+        self.status_code() == Some(401)
+    }
+
+    /// The server returned 403 Forbidden.
+    pub fn is_forbidden(&self) -> bool {
+        self.status_code() == Some(403)
+    }
+
+    /// The server returned 409 Conflict.
+    pub fn is_conflict(&self) -> bool {
+        self.status_code() == Some(409)
+    }
+
+    /// The server returned 429 Too Many Requests.
+    pub fn is_rate_limited(&self) -> bool {
+        self.status_code() == Some(429)
+    }
+
+    /// The server returned a 5xx status.
+    pub fn is_server_error(&self) -> bool {
+        self.status_code().is_some_and(|c| (500..600).contains(&c))
+    }
+
+    /// The underlying error with any context wrapper removed.
+    pub fn root(&self) -> &IgrisError {
+        match self {
+            IgrisError::Context { source, .. } => source.root(),
+            other => other,
+        }
+    }
 }
@@ -0,0 +1,51 @@
+//! Request/response callbacks for auditing and logging client traffic.
+//!
+//! Hooks registered with [`IgrisClientBuilder::on_request`] and
+//! [`IgrisClientBuilder::on_response`] run once per HTTP call made by the
+//! client. They receive a snapshot with the `Authorization` header redacted, so
+//! they are safe to log as-is.
+//!
+//! [`IgrisClientBuilder::on_request`]: crate::client::IgrisClientBuilder::on_request
+//! [`IgrisClientBuilder::on_response`]: crate::client::IgrisClientBuilder::on_response
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+use reqwest::Method;
+
+/// Callback invoked before a request is sent.
+pub type RequestHook = Arc<dyn Fn(&RequestInfo) + Send + Sync>;
+
+/// Callback invoked after a response (or transport failure) is received.
+pub type ResponseHook = Arc<dyn Fn(&ResponseInfo) + Send + Sync>;
+
+/// Snapshot of an outgoing request.
+#[derive(Debug, Clone)]
+pub struct RequestInfo {
+    pub method: Method,
+    /// Path relative to the client's base URL, e.g. `/v1/infer`.
+    pub path: String,
+    /// Request headers with `Authorization` redacted.
+    pub headers: HeaderMap,
+}
+
+/// Snapshot of a completed request.
+#[derive(Debug, Clone)]
+pub struct ResponseInfo {
+    pub method: Method,
+    pub path: String,
+    /// HTTP status, or `None` if no response was received.
+    pub status: Option<u16>,
+    /// Time from sending the request until the response body was read.
+    pub elapsed: Duration,
+    pub headers: HeaderMap,
+}
+
+pub(crate) fn redacted(headers: &HeaderMap) -> HeaderMap {
+    let mut headers = headers.clone();
+    if headers.contains_key(AUTHORIZATION) {
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("[REDACTED]"));
+    }
+    headers
+}
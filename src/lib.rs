@@ -1,25 +1,38 @@
 //! Igris Inertial Rust SDK — AI inference gateway client.
 
+pub mod auth;
+pub mod backoff;
 pub mod btree;
 pub mod client;
 pub mod containment;
 pub mod errors;
 pub mod fleet;
+pub mod hooks;
 pub mod providers;
 pub mod receipt;
 pub mod runtime;
+#[cfg(feature = "signing")]
+pub mod signing;
+#[cfg(feature = "metrics")]
+mod telemetry;
+#[cfg(feature = "chrono")]
+pub mod timestamp;
+pub mod transport;
 pub mod types;
 pub mod models;
 pub mod usage;
 pub mod vault;
 
+pub use auth::{AuthMethod, CredentialProvider, StaticToken};
+pub use backoff::Backoff;
 pub use btree::{
     action_node, condition_node, selector_node, sequence_node, BTreeDeployResult, BTreeRunOptions,
     BTreeRunResult, BTreeValidateResult, BehaviorTree,
 };
-pub use client::IgrisClient;
+pub use client::{IgrisClient, RequestOptions};
 pub use containment::{Bounds, ViolationKind, ViolationRecord};
 pub use errors::IgrisError;
+pub use hooks::{RequestInfo, ResponseInfo};
 pub use models::ModelManager;
 pub use receipt::verify_receipt;
 pub use runtime::{Runtime, RuntimeBuilder, RuntimeConfig};
@@ -32,6 +32,12 @@ impl<'a> ProviderManager<'a> {
         self.client.request(reqwest::Method::PUT, &format!("/v1/providers/{}", id), Some(config)).await
     }
 
+    /// Partially update a provider: only the fields present in `updates` are
+    /// changed, unlike [`ProviderManager::update`], which replaces the provider.
+    pub async fn patch(&self, id: &str, updates: &serde_json::Value) -> Result<Provider, IgrisError> {
+        self.client.request(reqwest::Method::PATCH, &format!("/v1/providers/{}", id), Some(updates)).await
+    }
+
     pub async fn delete(&self, id: &str) -> Result<(), IgrisError> {
         self.client.request_no_body(reqwest::Method::DELETE, &format!("/v1/providers/{}", id)).await
     }
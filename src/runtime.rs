@@ -4,6 +4,7 @@ use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
 use reqwest::Client;
 use serde::Serialize;
 
+use crate::client::ResponseDecoder;
 use crate::containment::{Bounds, ViolationRecord};
 use crate::errors::IgrisError;
 use crate::types::{InferRequest, InferResponse};
@@ -172,8 +173,8 @@ impl Runtime {
             });
         }
 
-        let data = resp.json().await?;
-        Ok(data)
+        let body = resp.bytes().await?;
+        ResponseDecoder::default().decode(&body)
     }
 
     pub(crate) async fn local_request<T: serde::de::DeserializeOwned>(
@@ -182,6 +183,7 @@ impl Runtime {
         path: &str,
         body: Option<&impl Serialize>,
     ) -> Result<T, IgrisError> {
+        let operation = format!("{} {}", method, path);
         let url = self.local_url(path);
         let mut req = self.local_http.request(method, &url);
         if let Some(b) = body {
@@ -195,8 +197,11 @@ impl Runtime {
                 );
             }
         }
-        let resp = req.send().await?;
-        Self::handle_response(resp).await
+        let result = match req.send().await {
+            Ok(resp) => Self::handle_response(resp).await,
+            Err(e) => Err(e.into()),
+        };
+        result.map_err(|e| e.with_context(operation))
     }
 
     async fn cloud_request<T: serde::de::DeserializeOwned>(
@@ -205,6 +210,7 @@ impl Runtime {
         path: &str,
         body: Option<&impl Serialize>,
     ) -> Result<T, IgrisError> {
+        let operation = format!("{} {} (cloud)", method, path);
         let cloud_http = self
             .cloud_http
             .as_ref()
@@ -221,8 +227,11 @@ impl Runtime {
         if let Some(b) = body {
             req = req.json(b);
         }
-        let resp = req.send().await?;
-        Self::handle_response(resp).await
+        let result = match req.send().await {
+            Ok(resp) => Self::handle_response(resp).await,
+            Err(e) => Err(e.into()),
+        };
+        result.map_err(|e| e.with_context(operation))
     }
 
     async fn request_with_fallback<T: serde::de::DeserializeOwned>(
@@ -233,8 +242,10 @@ impl Runtime {
     ) -> Result<T, IgrisError> {
         match self.local_request(method.clone(), path, body).await {
             Ok(result) => Ok(result),
-            Err(IgrisError::Network(_))
-                if self.config.auto_fallback && self.config.cloud_url.is_some() =>
+            Err(e)
+                if matches!(e.root(), IgrisError::Network(_))
+                    && self.config.auto_fallback
+                    && self.config.cloud_url.is_some() =>
             {
                 self.cloud_request(method, path, body).await
             }
@@ -0,0 +1,119 @@
+//! Request metrics emitted through the `metrics` facade (`metrics` feature).
+//!
+//! - `igris_requests_total{method, path, status}` — counter
+//! - `igris_request_duration_seconds{method, path}` — histogram
+//!
+//! `path` is templated (e.g. `/v1/providers/{id}`) so resource identifiers do
+//! not explode label cardinality. Paths the SDK does not know about, such as
+//! those passed to [`IgrisClient::request`](crate::IgrisClient::request), are
+//! reported as `"other"`. `status` is the HTTP status code, or
+//! `"error"` when no response was received.
+
+use std::time::Duration;
+
+/// Every route the SDK calls, keyed by method. `{...}` segments match any
+/// non-empty identifier.
+const ROUTES: &[(&str, &str)] = &[
+    ("POST", "/v1/auth/login"),
+    ("POST", "/v1/auth/refresh"),
+    ("POST", "/v1/auth/logout"),
+    ("POST", "/v1/infer"),
+    ("POST", "/v1/chat/completions"),
+    ("GET", "/v1/models"),
+    ("GET", "/v1/health"),
+    ("HEAD", "/v1/health"),
+    ("POST", "/v1/providers/register"),
+    ("POST", "/v1/providers/test"),
+    ("GET", "/v1/providers"),
+    ("GET", "/v1/providers/stats"),
+    ("PUT", "/v1/providers/{id}"),
+    ("PATCH", "/v1/providers/{id}"),
+    ("DELETE", "/v1/providers/{id}"),
+    ("GET", "/v1/providers/{id}/health"),
+    ("POST", "/v1/vault/keys"),
+    ("GET", "/v1/vault/keys"),
+    ("DELETE", "/v1/vault/keys/{provider}"),
+    ("POST", "/v1/vault/keys/{provider}/rotate"),
+    ("GET", "/v1/usage"),
+    ("GET", "/v1/usage/history"),
+    ("GET", "/v1/audit"),
+    ("POST", "/api/fleet/register"),
+    ("GET", "/api/fleet/agents"),
+    ("GET", "/api/fleet/health"),
+    ("POST", "/api/fleet/{fleet_id}/telemetry"),
+];
+
+/// Label used for paths that match no known route.
+const OTHER_PATH: &str = "other";
+
+/// Map a concrete request path to its route template, or [`OTHER_PATH`].
+pub(crate) fn path_template(method: &str, path: &str) -> String {
+    let path = path.split('?').next().unwrap_or(path);
+    let segments: Vec<&str> = path.split('/').collect();
+
+    ROUTES
+        .iter()
+        .filter(|(m, _)| *m == method)
+        .map(|(_, template)| *template)
+        .find(|template| {
+            let parts: Vec<&str> = template.split('/').collect();
+            parts.len() == segments.len()
+                && parts
+                    .iter()
+                    .zip(&segments)
+                    .all(|(p, s)| (p.starts_with('{') && !s.is_empty()) || p == s)
+        })
+        .unwrap_or(OTHER_PATH)
+        .to_string()
+}
+
+pub(crate) fn record_request(method: &str, path: &str, status: Option<u16>, elapsed: Duration) {
+    let path = path_template(method, path);
+    let status = status.map_or_else(|| "error".to_string(), |s| s.to_string());
+
+    metrics::counter!(
+        "igris_requests_total",
+        "method" => method.to_string(),
+        "path" => path.clone(),
+        "status" => status,
+    )
+    .increment(1);
+    metrics::histogram!(
+        "igris_request_duration_seconds",
+        "method" => method.to_string(),
+        "path" => path,
+    )
+    .record(elapsed.as_secs_f64());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_templates_identifiers() {
+        assert_eq!(path_template("DELETE", "/v1/providers/p-123"), "/v1/providers/{id}");
+        assert_eq!(
+            path_template("GET", "/v1/providers/p-123/health"),
+            "/v1/providers/{id}/health"
+        );
+        assert_eq!(
+            path_template("POST", "/v1/vault/keys/openai/rotate"),
+            "/v1/vault/keys/{provider}/rotate"
+        );
+    }
+
+    #[test]
+    fn test_fixed_paths_unchanged() {
+        assert_eq!(path_template("POST", "/v1/providers/register"), "/v1/providers/register");
+        assert_eq!(path_template("GET", "/v1/providers/stats"), "/v1/providers/stats");
+        assert_eq!(path_template("GET", "/v1/health"), "/v1/health");
+    }
+
+    #[test]
+    fn test_unknown_paths_collapsed() {
+        assert_eq!(path_template("GET", "/v1/new-endpoint"), "other");
+        assert_eq!(path_template("GET", "/v1/things/t-123"), "other");
+        assert_eq!(path_template("POST", "/v1/health"), "other");
+    }
+}
@@ -0,0 +1,20 @@
+//! Timestamp parsing for response types (`chrono` feature).
+//!
+//! Response structs keep timestamps as the raw strings the server sent; this
+//! feature adds `*_parsed()` accessors such as [`crate::VaultKey::created_at_parsed`]
+//! that return `DateTime<Utc>`. Inputs without a timezone offset (e.g.
+//! `2026-02-18T09:00:00`) are taken as UTC.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+/// Parse an RFC 3339 timestamp, falling back to UTC for offset-less inputs.
+/// Returns `None` if the value is not a recognised timestamp.
+pub fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"]
+        .iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(value, fmt).ok())
+        .map(|naive| naive.and_utc())
+}
@@ -0,0 +1,147 @@
+//! HTTP transport abstraction used by [`IgrisClient`](crate::IgrisClient).
+//!
+//! The client builds a [`TransportRequest`] (method, absolute URL, headers and
+//! an already-serialised body) and hands it to a [`Transport`], which returns
+//! the raw status, headers and body. Status mapping and deserialisation stay in
+//! the client, so every transport gets the same error handling.
+//!
+//! Most users never touch this module: [`IgrisClient::builder`] uses
+//! [`ReqwestTransport`] by default. Implement [`Transport`] yourself to plug in
+//! a different HTTP stack (e.g. a WASM `fetch` backend), wrap requests with
+//! custom behaviour, or fake the network in tests.
+//!
+//! ## Stability
+//!
+//! This is a semi-public extension point. [`TransportRequest`] is
+//! `#[non_exhaustive]` and may gain fields in minor releases; transports should
+//! forward what they understand and ignore the rest.
+//!
+//! ```rust,ignore
+//! use igris_inertial::transport::{Transport, TransportFuture, TransportRequest, ReqwestTransport};
+//!
+//! struct Logging(ReqwestTransport);
+//!
+//! impl Transport for Logging {
+//!     fn execute(&self, request: TransportRequest) -> TransportFuture<'_> {
+//!         println!("{} {}", request.method, request.url);
+//!         self.0.execute(request)
+//!     }
+//! }
+//!
+//! let client = IgrisClient::builder("https://api.igris-inertial.com")
+//!     .transport(Logging(ReqwestTransport::new(reqwest::Client::new())))
+//!     .build()?;
+//! ```
+//!
+//! [`IgrisClient::builder`]: crate::IgrisClient::builder
+
+use std::future::Future;
+use std::pin::Pin;
+
+use reqwest::header::HeaderMap;
+use reqwest::Method;
+
+use crate::errors::IgrisError;
+
+#[cfg(feature = "testing")]
+pub mod mock;
+
+/// Boxed future returned by [`Transport::execute`].
+pub type TransportFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<TransportResponse, IgrisError>> + Send + 'a>>;
+
+/// A fully prepared HTTP request.
+///
+/// `url` is absolute and `headers` already include authentication and, when a
+/// body is present, `Content-Type`.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct TransportRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: HeaderMap,
+    pub body: Option<Vec<u8>>,
+}
+
+/// The raw result of executing a [`TransportRequest`].
+#[derive(Debug, Clone)]
+pub struct TransportResponse {
+    pub status: u16,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+/// Executes HTTP requests on behalf of the client.
+///
+/// Implementations return `Ok` for any HTTP status, including 4xx/5xx; only
+/// failures to obtain a response at all (connection, TLS, timeout) are errors.
+pub trait Transport: Send + Sync {
+    fn execute(&self, request: TransportRequest) -> TransportFuture<'_>;
+}
+
+/// Default transport backed by `reqwest`.
+///
+/// Pass a preconfigured `reqwest::Client` to control proxies, TLS roots and
+/// similar settings the builder does not expose. The client's own timeout
+/// applies instead of [`IgrisClientBuilder::timeout`](crate::client::IgrisClientBuilder::timeout).
+#[derive(Clone)]
+pub struct ReqwestTransport {
+    http: reqwest::Client,
+    max_response_bytes: Option<usize>,
+}
+
+impl ReqwestTransport {
+    pub fn new(http: reqwest::Client) -> Self {
+        Self { http, max_response_bytes: None }
+    }
+
+    /// Fail with [`IgrisError::ResponseTooLarge`] instead of buffering a
+    /// success body larger than `limit` bytes. Error responses (status 400 and
+    /// above) are cut to `limit` bytes instead, so their status is kept.
+    pub fn max_response_bytes(mut self, limit: usize) -> Self {
+        self.max_response_bytes = Some(limit);
+        self
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn execute(&self, request: TransportRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let mut req = self
+                .http
+                .request(request.method, &request.url)
+                .headers(request.headers);
+            if let Some(body) = request.body {
+                req = req.body(body);
+            }
+            let mut resp = req.send().await?;
+            let status = resp.status().as_u16();
+            let headers = resp.headers().clone();
+            let body = match self.max_response_bytes {
+                Some(limit) => {
+                    // Error bodies are only used for messages, so keep the
+                    // first `limit` bytes rather than hiding the status.
+                    let is_error = status >= 400;
+                    let too_large = || IgrisError::ResponseTooLarge { limit };
+                    if !is_error && resp.content_length().is_some_and(|len| len > limit as u64) {
+                        return Err(too_large());
+                    }
+                    let mut body = Vec::new();
+                    while let Some(chunk) = resp.chunk().await? {
+                        if body.len() + chunk.len() > limit {
+                            if !is_error {
+                                return Err(too_large());
+                            }
+                            body.extend_from_slice(&chunk[..limit - body.len()]);
+                            break;
+                        }
+                        body.extend_from_slice(&chunk);
+                    }
+                    body
+                }
+                None => resp.bytes().await?.to_vec(),
+            };
+            Ok(TransportResponse { status, headers, body })
+        })
+    }
+}
@@ -0,0 +1,105 @@
+//! In-memory [`Transport`] for testing code that uses [`IgrisClient`](crate::IgrisClient).
+//!
+//! Requires the `testing` feature.
+//!
+//! ```rust,ignore
+//! use igris_inertial::transport::mock::MockTransport;
+//! use igris_inertial::IgrisClient;
+//!
+//! let mock = MockTransport::new();
+//! mock.on("GET", "/v1/health").respond(200, serde_json::json!({"status": "ok"}));
+//!
+//! let client = IgrisClient::builder("https://api.igris-inertial.com")
+//!     .transport(mock.clone())
+//!     .build()?;
+//! assert_eq!(client.health().await?.status, "ok");
+//! assert_eq!(mock.requests().len(), 1);
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+
+use super::{Transport, TransportFuture, TransportRequest, TransportResponse};
+use crate::errors::IgrisError;
+
+/// Programmable transport returning canned responses keyed by method and path.
+///
+/// Clones share the same routes and request log, so a test can keep one
+/// handle for assertions while the client owns another.
+#[derive(Clone, Default)]
+pub struct MockTransport {
+    inner: Arc<Mutex<MockState>>,
+}
+
+#[derive(Default)]
+struct MockState {
+    routes: HashMap<(String, String), TransportResponse>,
+    requests: Vec<TransportRequest>,
+}
+
+/// Route being configured by [`MockTransport::on`].
+pub struct MockRoute<'a> {
+    transport: &'a MockTransport,
+    method: String,
+    path: String,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start configuring the response for `method` and `path`.
+    ///
+    /// `path` is matched against the request URL path, ignoring any query
+    /// string. Later registrations for the same route replace earlier ones.
+    pub fn on(&self, method: &str, path: &str) -> MockRoute<'_> {
+        MockRoute {
+            transport: self,
+            method: method.to_ascii_uppercase(),
+            path: path.to_string(),
+        }
+    }
+
+    /// All requests received so far, in order.
+    pub fn requests(&self) -> Vec<TransportRequest> {
+        self.inner.lock().unwrap().requests.clone()
+    }
+}
+
+impl MockRoute<'_> {
+    /// Respond with `status` and a JSON body.
+    pub fn respond(self, status: u16, body: serde_json::Value) {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        self.respond_raw(status, headers, body.to_string().into_bytes());
+    }
+
+    /// Respond with `status`, explicit headers and a raw body.
+    pub fn respond_raw(self, status: u16, headers: HeaderMap, body: Vec<u8>) {
+        self.transport.inner.lock().unwrap().routes.insert(
+            (self.method, self.path),
+            TransportResponse { status, headers, body },
+        );
+    }
+}
+
+impl Transport for MockTransport {
+    fn execute(&self, request: TransportRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let path = reqwest::Url::parse(&request.url)
+                .map(|u| u.path().to_string())
+                .unwrap_or_else(|_| request.url.clone());
+            let key = (request.method.as_str().to_string(), path);
+
+            let mut state = self.inner.lock().unwrap();
+            state.requests.push(request);
+            state.routes.get(&key).cloned().ok_or_else(|| IgrisError::Api {
+                message: format!("no mock response for {} {}", key.0, key.1),
+                status_code: 0,
+            })
+        })
+    }
+}
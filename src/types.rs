@@ -23,7 +23,7 @@ pub struct PolicyOverride {
     pub timeout_ms: Option<u64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct InferRequest {
     pub model: String,
     pub messages: Vec<Message>,
@@ -138,7 +138,7 @@ pub struct HealthResponse {
     pub uptime: Option<f64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct ProviderConfig {
     pub name: String,
     #[serde(rename = "type")]
@@ -157,6 +157,21 @@ pub struct ProviderConfig {
     pub enabled: Option<bool>,
 }
 
+impl std::fmt::Debug for ProviderConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProviderConfig")
+            .field("name", &self.name)
+            .field("provider_type", &self.provider_type)
+            .field("api_key", &self.api_key.as_deref().map(crate::auth::mask))
+            .field("base_url", &self.base_url)
+            .field("models", &self.models)
+            .field("priority", &self.priority)
+            .field("weight", &self.weight)
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Provider {
     pub id: String,
@@ -192,6 +207,14 @@ pub struct HealthStatus {
     pub last_check: Option<String>,
 }
 
+#[cfg(feature = "chrono")]
+impl HealthStatus {
+    /// [`HealthStatus::last_check`] parsed with [`crate::timestamp::parse_timestamp`].
+    pub fn last_check_parsed(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.last_check.as_deref().and_then(crate::timestamp::parse_timestamp)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VaultKey {
     pub provider: String,
@@ -203,7 +226,20 @@ pub struct VaultKey {
     pub rotated_at: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg(feature = "chrono")]
+impl VaultKey {
+    /// [`VaultKey::created_at`] parsed with [`crate::timestamp::parse_timestamp`].
+    pub fn created_at_parsed(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.created_at.as_deref().and_then(crate::timestamp::parse_timestamp)
+    }
+
+    /// [`VaultKey::rotated_at`] parsed with [`crate::timestamp::parse_timestamp`].
+    pub fn rotated_at_parsed(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.rotated_at.as_deref().and_then(crate::timestamp::parse_timestamp)
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
 pub struct VaultStoreRequest {
     pub provider: String,
     pub api_key: String,
@@ -211,6 +247,16 @@ pub struct VaultStoreRequest {
     pub config: Option<HashMap<String, serde_json::Value>>,
 }
 
+impl std::fmt::Debug for VaultStoreRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VaultStoreRequest")
+            .field("provider", &self.provider)
+            .field("api_key", &crate::auth::mask(&self.api_key))
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Usage {
     #[serde(default)]
@@ -241,6 +287,14 @@ pub struct AuditEntry {
     pub details: Option<HashMap<String, serde_json::Value>>,
 }
 
+#[cfg(feature = "chrono")]
+impl AuditEntry {
+    /// [`AuditEntry::timestamp`] parsed with [`crate::timestamp::parse_timestamp`].
+    pub fn timestamp_parsed(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::timestamp::parse_timestamp(&self.timestamp)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FleetAgent {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -256,6 +310,14 @@ pub struct FleetAgent {
     pub registered_at: Option<String>,
 }
 
+#[cfg(feature = "chrono")]
+impl FleetAgent {
+    /// [`FleetAgent::registered_at`] parsed with [`crate::timestamp::parse_timestamp`].
+    pub fn registered_at_parsed(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.registered_at.as_deref().and_then(crate::timestamp::parse_timestamp)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FleetHealth {
     #[serde(default)]
@@ -267,19 +329,3 @@ pub struct FleetHealth {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub agents: Option<Vec<serde_json::Value>>,
 }
-
-impl Default for InferRequest {
-    fn default() -> Self {
-        Self {
-            model: String::new(),
-            messages: Vec::new(),
-            stream: None,
-            max_tokens: None,
-            temperature: None,
-            top_p: None,
-            stop: None,
-            policy: None,
-            metadata: None,
-        }
-    }
-}
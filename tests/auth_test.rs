@@ -0,0 +1,261 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use igris_inertial::auth::CredentialFuture;
+use igris_inertial::{AuthMethod, CredentialProvider, IgrisClient, IgrisError};
+use mockito::Matcher;
+
+async fn token_mock(
+    server: &mut mockito::ServerGuard,
+    expires_in: u64,
+    hits: usize,
+) -> mockito::Mock {
+    server
+        .mock("POST", "/oauth/token")
+        .match_header("content-type", "application/x-www-form-urlencoded")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("grant_type".into(), "client_credentials".into()),
+            Matcher::UrlEncoded("client_id".into(), "my-client".into()),
+            Matcher::UrlEncoded("client_secret".into(), "s3cret".into()),
+        ]))
+        .with_status(200)
+        .with_body(
+            serde_json::json!({
+                "access_token": "tok-abc",
+                "token_type": "Bearer",
+                "expires_in": expires_in,
+            })
+            .to_string(),
+        )
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+async fn health_mock(server: &mut mockito::ServerGuard, hits: usize) -> mockito::Mock {
+    server
+        .mock("GET", "/v1/health")
+        .match_header("authorization", "Bearer tok-abc")
+        .with_status(200)
+        .with_body(r#"{"status":"ok"}"#)
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_oauth2_token_attached_and_cached() {
+    let mut server = mockito::Server::new_async().await;
+    let token = token_mock(&mut server, 3600, 1).await;
+    let health = health_mock(&mut server, 2).await;
+
+    let client = IgrisClient::with_oauth2(
+        server.url(),
+        format!("{}/oauth/token", server.url()),
+        "my-client",
+        "s3cret",
+    )
+    .unwrap();
+
+    client.health().await.unwrap();
+    client.health().await.unwrap();
+
+    token.assert_async().await;
+    health.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_oauth2_token_refreshed_before_expiry() {
+    let mut server = mockito::Server::new_async().await;
+    // Expires within the refresh margin, so every request fetches a new token.
+    let token = token_mock(&mut server, 10, 2).await;
+    let health = health_mock(&mut server, 2).await;
+
+    let client = IgrisClient::builder(server.url())
+        .auth(AuthMethod::OAuth2 {
+            token_url: format!("{}/oauth/token", server.url()),
+            client_id: "my-client".into(),
+            client_secret: "s3cret".into(),
+            scopes: vec!["inference".into(), "vault:read".into()],
+        })
+        .build()
+        .unwrap();
+
+    client.health().await.unwrap();
+    client.health().await.unwrap();
+
+    token.assert_async().await;
+    health.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_oauth2_token_failure() {
+    let mut server = mockito::Server::new_async().await;
+    let _token = server
+        .mock("POST", "/oauth/token")
+        .with_status(401)
+        .with_body(r#"{"error":"invalid_client"}"#)
+        .create_async()
+        .await;
+    let health = server.mock("GET", "/v1/health").expect(0).create_async().await;
+
+    let client = IgrisClient::with_oauth2(
+        server.url(),
+        format!("{}/oauth/token", server.url()),
+        "my-client",
+        "wrong",
+    )
+    .unwrap();
+
+    let err = client.health().await.unwrap_err();
+    assert!(matches!(err.root(), IgrisError::Auth { .. }));
+    assert!(err.to_string().contains("invalid_client"));
+    health.assert_async().await;
+}
+
+#[test]
+fn test_oauth2_insecure_token_url_rejected() {
+    let result = IgrisClient::with_oauth2(
+        "https://api.igris-inertial.com",
+        "http://auth.example.com/token",
+        "my-client",
+        "s3cret",
+    );
+    assert!(matches!(result, Err(IgrisError::Config { .. })));
+}
+
+struct Counter(AtomicUsize);
+
+impl CredentialProvider for Counter {
+    fn token(&self) -> CredentialFuture<'_> {
+        Box::pin(async move { Ok(format!("tok-{}", self.0.fetch_add(1, Ordering::SeqCst) + 1)) })
+    }
+}
+
+#[tokio::test]
+async fn test_custom_credential_provider_called_per_request() {
+    let mut server = mockito::Server::new_async().await;
+    let first = server
+        .mock("GET", "/v1/health")
+        .match_header("authorization", "Bearer tok-1")
+        .with_body(r#"{"status":"ok"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let second = server
+        .mock("GET", "/v1/health")
+        .match_header("authorization", "Bearer tok-2")
+        .with_body(r#"{"status":"ok"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = IgrisClient::builder(server.url())
+        .credentials(Counter(AtomicUsize::new(0)))
+        .build()
+        .unwrap();
+
+    client.health().await.unwrap();
+    client.health().await.unwrap();
+
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+struct Failing;
+
+impl CredentialProvider for Failing {
+    fn token(&self) -> CredentialFuture<'_> {
+        Box::pin(async { Err(IgrisError::Auth { message: "vault sealed".into() }) })
+    }
+}
+
+#[tokio::test]
+async fn test_custom_credential_provider_error() {
+    let mut server = mockito::Server::new_async().await;
+    let health = server.mock("GET", "/v1/health").expect(0).create_async().await;
+
+    let client = IgrisClient::builder(server.url()).credentials(Failing).build().unwrap();
+
+    let err = client.health().await.unwrap_err();
+    assert!(matches!(err.root(), IgrisError::Auth { .. }));
+    health.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_oauth2_token_refetched_after_rejection() {
+    let mut server = mockito::Server::new_async().await;
+    // No `expires_in`: the token is cached for a default lifetime.
+    let token = server
+        .mock("POST", "/oauth/token")
+        .with_body(r#"{"access_token":"tok-abc","token_type":"Bearer"}"#)
+        .expect(2)
+        .create_async()
+        .await;
+    let rejected = server
+        .mock("GET", "/v1/health")
+        .with_status(401)
+        .with_body(r#"{"error":"token expired"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let health = health_mock(&mut server, 2).await;
+
+    let client = IgrisClient::with_oauth2(
+        server.url(),
+        format!("{}/oauth/token", server.url()),
+        "my-client",
+        "s3cret",
+    )
+    .unwrap();
+
+    let err = client.health().await.unwrap_err();
+    assert!(err.is_unauthorized());
+    // The rejected token was dropped, so this fetches a new one, which is then
+    // cached for the following call.
+    client.health().await.unwrap();
+    client.health().await.unwrap();
+
+    token.assert_async().await;
+    rejected.assert_async().await;
+    health.assert_async().await;
+}
+
+struct Slow;
+
+impl CredentialProvider for Slow {
+    fn token(&self) -> CredentialFuture<'_> {
+        Box::pin(async {
+            tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+            Ok("late".to_string())
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_slow_credentials_bounded_by_deadline_and_timeout() {
+    use std::time::{Duration, Instant};
+
+    let client = IgrisClient::builder("https://gateway.example").credentials(Slow).build().unwrap();
+
+    let started = Instant::now();
+    let err = client
+        .with_deadline(Instant::now() + Duration::from_millis(100))
+        .health()
+        .await
+        .unwrap_err();
+    assert!(matches!(err.root(), IgrisError::Timeout { .. }));
+    assert!(err.to_string().contains("fetching credentials"));
+    assert!(started.elapsed() < Duration::from_secs(1));
+
+    let started = Instant::now();
+    let options = igris_inertial::RequestOptions {
+        timeout: Some(Duration::from_millis(100)),
+        ..Default::default()
+    };
+    let err = client
+        .infer_with_options(&igris_inertial::InferRequest::default(), &options)
+        .await
+        .unwrap_err();
+    assert!(matches!(err.root(), IgrisError::Timeout { .. }));
+    assert!(started.elapsed() < Duration::from_secs(1));
+}
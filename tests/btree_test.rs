@@ -345,7 +345,7 @@ async fn test_validate_server_error() {
     assert!(result.is_err());
     let err = result.unwrap_err();
     assert!(
-        matches!(err, igris_inertial::IgrisError::Api { status_code: 500, .. }),
+        matches!(err.root(), igris_inertial::IgrisError::Api { status_code: 500, .. }),
         "expected Api error with status 500, got: {:?}",
         err
     );
@@ -370,7 +370,7 @@ async fn test_run_validation_error() {
     assert!(result.is_err());
     let err = result.unwrap_err();
     assert!(
-        matches!(err, igris_inertial::IgrisError::Validation { status_code: 422, .. }),
+        matches!(err.root(), igris_inertial::IgrisError::Validation { status_code: 422, .. }),
         "expected Validation error with status 422, got: {:?}",
         err
     );
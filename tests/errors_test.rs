@@ -0,0 +1,45 @@
+use igris_inertial::IgrisError;
+
+fn api(status_code: u16) -> IgrisError {
+    IgrisError::Api { message: String::new(), status_code }
+}
+
+#[test]
+fn test_status_predicates() {
+    assert!(api(404).is_not_found());
+    assert!(!api(400).is_not_found());
+
+    let unauthorized = IgrisError::Authentication { message: String::new(), status_code: 401 };
+    assert!(unauthorized.is_unauthorized());
+    assert!(!unauthorized.is_forbidden());
+
+    let forbidden = IgrisError::Authentication { message: String::new(), status_code: 403 };
+    assert!(forbidden.is_forbidden());
+    assert!(!forbidden.is_unauthorized());
+
+    assert!(api(409).is_conflict());
+    assert!(!api(412).is_conflict());
+
+    assert!(IgrisError::RateLimit { message: String::new() }.is_rate_limited());
+    assert!(!api(503).is_rate_limited());
+
+    assert!(api(500).is_server_error());
+    assert!(api(503).is_server_error());
+    assert!(!api(499).is_server_error());
+    assert!(!api(0).is_server_error());
+}
+
+#[test]
+fn test_status_predicates_see_through_context() {
+    let err = api(404).with_context("GET /v1/providers/p-1/health");
+    assert!(err.is_not_found());
+    assert!(!err.is_server_error());
+}
+
+#[test]
+fn test_status_predicates_false_without_status() {
+    let err = IgrisError::Config { message: "bad".into() };
+    assert!(!err.is_not_found());
+    assert!(!err.is_unauthorized());
+    assert!(!err.is_server_error());
+}
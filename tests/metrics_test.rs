@@ -0,0 +1,48 @@
+#![cfg(feature = "metrics")]
+
+use igris_inertial::IgrisClient;
+use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+use metrics_util::MetricKind;
+
+#[tokio::test]
+async fn test_request_metrics_emitted() {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    recorder.install().unwrap();
+
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("DELETE", "/v1/providers/p-1")
+        .with_status(204)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = IgrisClient::new(server.url(), "test-key").unwrap();
+    client.providers().delete("p-1").await.unwrap();
+    client.providers().delete("p-1").await.unwrap();
+
+    let snapshot = snapshotter.snapshot().into_vec();
+    let (counter, _, _, count) = snapshot
+        .iter()
+        .find(|(k, _, _, _)| {
+            k.kind() == MetricKind::Counter && k.key().name() == "igris_requests_total"
+        })
+        .expect("request counter recorded");
+    assert_eq!(*count, DebugValue::Counter(2));
+
+    let labels: Vec<(String, String)> = counter
+        .key()
+        .labels()
+        .map(|l| (l.key().to_string(), l.value().to_string()))
+        .collect();
+    assert!(labels.contains(&("method".into(), "DELETE".into())));
+    assert!(labels.contains(&("path".into(), "/v1/providers/{id}".into())));
+    assert!(labels.contains(&("status".into(), "204".into())));
+
+    let (_, _, _, durations) = snapshot
+        .iter()
+        .find(|(k, _, _, _)| k.key().name() == "igris_request_duration_seconds")
+        .expect("duration histogram recorded");
+    assert!(matches!(durations, DebugValue::Histogram(v) if v.len() == 2));
+}
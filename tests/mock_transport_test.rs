@@ -0,0 +1,90 @@
+#![cfg(feature = "testing")]
+
+use igris_inertial::transport::mock::MockTransport;
+use igris_inertial::{IgrisClient, IgrisError, InferRequest, Message};
+
+fn client_with(mock: &MockTransport) -> IgrisClient {
+    IgrisClient::builder("https://api.igris-inertial.com")
+        .api_key("test-key")
+        .transport(mock.clone())
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_mock_health() {
+    let mock = MockTransport::new();
+    mock.on("GET", "/v1/health")
+        .respond(200, serde_json::json!({"status": "ok", "version": "2.2.0"}));
+
+    let client = client_with(&mock);
+    let health = client.health().await.unwrap();
+
+    assert_eq!(health.status, "ok");
+    assert_eq!(health.version.as_deref(), Some("2.2.0"));
+}
+
+#[tokio::test]
+async fn test_mock_records_requests() {
+    let mock = MockTransport::new();
+    mock.on("POST", "/v1/infer").respond(
+        200,
+        serde_json::json!({
+            "id": "resp-1",
+            "object": "chat.completion",
+            "created": 1700000000,
+            "model": "gpt-4",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": "Hi"}
+            }]
+        }),
+    );
+
+    let client = client_with(&mock);
+    let resp = client
+        .infer(&InferRequest {
+            model: "gpt-4".to_string(),
+            messages: vec![Message {
+                role: "user".to_string(),
+                content: "Hello".to_string(),
+                content_parts: None,
+            }],
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(resp.choices[0].message.content, "Hi");
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, reqwest::Method::POST);
+    assert_eq!(requests[0].url, "https://api.igris-inertial.com/v1/infer");
+    assert_eq!(requests[0].headers["authorization"], "Bearer test-key");
+    let body: serde_json::Value = serde_json::from_slice(requests[0].body.as_ref().unwrap()).unwrap();
+    assert_eq!(body["model"], "gpt-4");
+}
+
+#[tokio::test]
+async fn test_mock_error_status_is_mapped() {
+    let mock = MockTransport::new();
+    mock.on("DELETE", "/v1/providers/p1")
+        .respond(401, serde_json::json!({"error": "bad key"}));
+
+    let client = client_with(&mock);
+    let err = client.providers().delete("p1").await.unwrap_err();
+
+    assert!(matches!(
+        err.root(),
+        IgrisError::Authentication { status_code: 401, .. }
+    ));
+}
+
+#[tokio::test]
+async fn test_mock_unmatched_route() {
+    let mock = MockTransport::new();
+    let client = client_with(&mock);
+
+    assert!(client.list_models().await.is_err());
+    assert_eq!(mock.requests().len(), 1);
+}
@@ -127,6 +127,29 @@ async fn test_chat_local_only_no_fallback() {
     mock.assert_async().await;
 }
 
+#[tokio::test]
+async fn test_chat_local_invalid_response() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("POST", "/v1/chat/completions")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"id":"rt-123","choices":"not-a-list"}"#)
+        .create_async()
+        .await;
+
+    let runtime = Runtime::new(server.url()).unwrap();
+    let err = runtime.chat_local(&sample_infer_request()).await.unwrap_err();
+
+    match err.root() {
+        igris_inertial::IgrisError::InvalidResponse { type_name, body, .. } => {
+            assert!(type_name.ends_with("InferResponse"));
+            assert_eq!(body, r#"{"id":"rt-123","choices":"not-a-list"}"#);
+        }
+        other => panic!("expected InvalidResponse, got {:?}", other),
+    }
+}
+
 #[tokio::test]
 async fn test_chat_fallback_to_cloud() {
     // Local server returns connection error (unreachable port).
@@ -278,9 +301,10 @@ async fn test_chat_api_error() {
     assert!(result.is_err());
     let err = result.unwrap_err();
     assert!(
-        matches!(err, igris_inertial::IgrisError::Api { status_code: 500, .. }),
+        matches!(err.root(), igris_inertial::IgrisError::Api { status_code: 500, .. }),
         "expected Api error with status 500, got: {:?}",
         err
     );
+    assert_eq!(err.operation(), Some("POST /v1/chat/completions"));
     mock.assert_async().await;
 }
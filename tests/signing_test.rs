@@ -0,0 +1,70 @@
+#![cfg(feature = "signing")]
+
+use std::sync::{Arc, Mutex};
+
+use igris_inertial::signing::{RequestSigner, SigningAlgorithm};
+use igris_inertial::transport::{Transport, TransportFuture, TransportRequest, TransportResponse};
+use igris_inertial::{IgrisClient, InferRequest};
+use reqwest::header::HeaderMap;
+
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Vec<TransportRequest>>>);
+
+impl Transport for Capture {
+    fn execute(&self, request: TransportRequest) -> TransportFuture<'_> {
+        self.0.lock().unwrap().push(request);
+        Box::pin(async {
+            Ok(TransportResponse {
+                status: 200,
+                headers: HeaderMap::new(),
+                body: br#"{"id":"r1","object":"chat.completion","created":0,"model":"gpt-4","choices":[]}"#
+                    .to_vec(),
+            })
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_requests_are_signed() {
+    let capture = Capture::default();
+    let client = IgrisClient::builder("https://gateway.example/base")
+        .api_key("test-key")
+        .request_signing("top-secret", SigningAlgorithm::HmacSha256)
+        .transport(capture.clone())
+        .build()
+        .unwrap();
+
+    client
+        .infer(&InferRequest { model: "gpt-4".into(), ..Default::default() })
+        .await
+        .unwrap();
+
+    let requests = capture.0.lock().unwrap();
+    let request = &requests[0];
+    let header = |name: &str| request.headers.get(name).unwrap().to_str().unwrap().to_string();
+    let timestamp: u64 = header("x-timestamp").parse().unwrap();
+
+    let expected = RequestSigner::new("top-secret", SigningAlgorithm::HmacSha256).sign(
+        "POST",
+        "/base/v1/infer",
+        timestamp,
+        request.body.as_deref().unwrap(),
+    );
+    assert_eq!(header("x-signature"), expected);
+    assert_eq!(header("authorization"), "Bearer test-key");
+}
+
+#[tokio::test]
+async fn test_unsigned_by_default() {
+    let capture = Capture::default();
+    let client = IgrisClient::builder("https://gateway.example")
+        .transport(capture.clone())
+        .build()
+        .unwrap();
+
+    let _ = client.health().await;
+
+    let requests = capture.0.lock().unwrap();
+    assert!(!requests[0].headers.contains_key("x-signature"));
+    assert!(!requests[0].headers.contains_key("x-timestamp"));
+}
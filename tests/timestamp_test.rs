@@ -0,0 +1,58 @@
+use igris_inertial::{AuditEntry, VaultKey};
+
+fn vault_key_json() -> &'static str {
+    r#"{
+        "provider": "openai",
+        "key_id": "k-1",
+        "created_at": "2026-02-18T09:00:00+02:00",
+        "rotated_at": "2026-02-19T10:30:00"
+    }"#
+}
+
+#[test]
+fn test_timestamps_kept_as_strings() {
+    let key: VaultKey = serde_json::from_str(vault_key_json()).unwrap();
+    assert_eq!(key.created_at.as_deref(), Some("2026-02-18T09:00:00+02:00"));
+    assert_eq!(key.rotated_at.as_deref(), Some("2026-02-19T10:30:00"));
+
+    let out = serde_json::to_value(&key).unwrap();
+    assert_eq!(out["created_at"], "2026-02-18T09:00:00+02:00");
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_parsed_accessors_with_chrono() {
+    use chrono::{TimeZone, Utc};
+
+    let key: VaultKey = serde_json::from_str(vault_key_json()).unwrap();
+    assert_eq!(key.created_at_parsed(), Some(Utc.with_ymd_and_hms(2026, 2, 18, 7, 0, 0).unwrap()));
+    // Offset-less timestamps are interpreted as UTC.
+    assert_eq!(key.rotated_at_parsed(), Some(Utc.with_ymd_and_hms(2026, 2, 19, 10, 30, 0).unwrap()));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_invalid_timestamp_parses_to_none() {
+    let json = r#"{"id": "a1", "action": "login", "timestamp": "yesterday"}"#;
+    let entry: AuditEntry = serde_json::from_str(json).unwrap();
+    assert_eq!(entry.timestamp, "yesterday");
+    assert!(entry.timestamp_parsed().is_none());
+}
+
+#[test]
+fn test_missing_optional_timestamps() {
+    let key: VaultKey = serde_json::from_str(r#"{"provider": "anthropic"}"#).unwrap();
+    assert!(key.created_at.is_none());
+    assert!(key.rotated_at.is_none());
+
+    let out = serde_json::to_string(&key).unwrap();
+    assert!(!out.contains("created_at"));
+}
+
+#[test]
+fn test_audit_entry_timestamp_roundtrip() {
+    let json = r#"{"id": "a1", "action": "login", "timestamp": "2026-02-18T09:00:00Z"}"#;
+    let entry: AuditEntry = serde_json::from_str(json).unwrap();
+    let out = serde_json::to_value(&entry).unwrap();
+    assert_eq!(out["timestamp"], "2026-02-18T09:00:00Z");
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "tracing")]
+
+use igris_inertial::IgrisClient;
+use tracing_test::traced_test;
+
+#[tokio::test]
+#[traced_test]
+async fn test_request_span_fields() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/v1/health")
+        .with_status(200)
+        .with_header("x-request-id", "req-42")
+        .with_body(r#"{"status":"ok"}"#)
+        .create_async()
+        .await;
+
+    let client = IgrisClient::new(server.url(), "test-key").unwrap();
+    client.health().await.unwrap();
+
+    assert!(logs_contain("igris.request"));
+    assert!(logs_contain("http.method=GET"));
+    assert!(logs_contain("http.path=\"/v1/health\""));
+    assert!(logs_contain("http.status_code=200"));
+    assert!(logs_contain("request_id=\"req-42\""));
+    assert!(logs_contain("elapsed_ms="));
+}
+
+#[tokio::test]
+#[traced_test]
+async fn test_request_span_records_error() {
+    let mut server = mockito::Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/v1/models")
+        .with_status(503)
+        .with_body("unavailable")
+        .create_async()
+        .await;
+
+    let client = IgrisClient::new(server.url(), "test-key").unwrap();
+    assert!(client.list_models().await.is_err());
+
+    assert!(logs_contain("http.status_code=503"));
+    assert!(logs_contain("unavailable"));
+}
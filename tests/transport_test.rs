@@ -0,0 +1,60 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use igris_inertial::transport::{
+    ReqwestTransport, Transport, TransportFuture, TransportRequest, TransportResponse,
+};
+use igris_inertial::IgrisClient;
+use reqwest::header::HeaderMap;
+
+struct Canned {
+    calls: Arc<AtomicUsize>,
+}
+
+impl Transport for Canned {
+    fn execute(&self, request: TransportRequest) -> TransportFuture<'_> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Box::pin(async move {
+            assert_eq!(request.url, "https://gateway.example/v1/health");
+            Ok(TransportResponse {
+                status: 200,
+                headers: HeaderMap::new(),
+                body: br#"{"status":"ok"}"#.to_vec(),
+            })
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_custom_transport() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let client = IgrisClient::builder("https://gateway.example")
+        .transport(Canned { calls: calls.clone() })
+        .build()
+        .unwrap();
+
+    let health = client.health().await.unwrap();
+    assert_eq!(health.status, "ok");
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_wrapped_reqwest_transport() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/v1/health")
+        .match_header("authorization", "Bearer test-key")
+        .with_status(200)
+        .with_body(r#"{"status":"ok"}"#)
+        .create_async()
+        .await;
+
+    let client = IgrisClient::builder(server.url())
+        .api_key("test-key")
+        .transport(ReqwestTransport::new(reqwest::Client::new()))
+        .build()
+        .unwrap();
+
+    assert_eq!(client.health().await.unwrap().status, "ok");
+    mock.assert_async().await;
+}